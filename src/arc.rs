@@ -0,0 +1,385 @@
+use crate::{Angle, Radians, Scalar};
+
+/// Closed arc of the circle, going counter-clockwise from `start` by `span`.
+///
+/// The span lies within `[0, 2pi]`, so an arc may cross the `+-180deg` seam,
+/// cover a single point or the full circle. Both ends are part of the arc.
+#[derive(Copy, Clone, Debug)]
+pub struct Arc<T = f64> {
+    start: Angle<T>,
+    span: T,
+}
+
+impl<T: Scalar> Arc<T> {
+    /// `span` is clamped to `[0, 2pi]`.
+    pub fn from_start_span(start: Angle<T>, span: Radians<T>) -> Self {
+        let span = if span > T::TAU {
+            T::TAU
+        } else if span > T::ZERO {
+            span
+        } else {
+            T::ZERO
+        };
+
+        Self { start, span }
+    }
+
+    /// Arc of `half_width` on both sides of `center`, `half_width` is clamped
+    /// to `[0, pi]`.
+    pub fn from_center(center: Angle<T>, half_width: Radians<T>) -> Self {
+        let half_width = if half_width > T::PI {
+            T::PI
+        } else if half_width > T::ZERO {
+            half_width
+        } else {
+            T::ZERO
+        };
+
+        Self::from_start_span(center - Angle::radians(half_width), half_width + half_width)
+    }
+
+    /// Arc going counter-clockwise from `start` to `end`.
+    pub fn between(start: Angle<T>, end: Angle<T>) -> Self {
+        Self::from_start_span(start, start.ccw_distance(&end).as_radians())
+    }
+
+    pub fn full() -> Self {
+        Self {
+            start: Angle::ZERO,
+            span: T::TAU,
+        }
+    }
+
+    pub fn start(&self) -> Angle<T> {
+        self.start
+    }
+
+    pub fn end(&self) -> Angle<T> {
+        self.start + Angle::radians(self.span)
+    }
+
+    pub fn center(&self) -> Angle<T> {
+        self.start + Angle::radians(self.span / T::from_f64(2.0))
+    }
+
+    pub fn span(&self) -> Radians<T> {
+        self.span
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.span >= T::TAU
+    }
+
+    pub fn contains(&self, angle: Angle<T>) -> bool {
+        self.offset(angle) <= self.span
+    }
+
+    /// Parts of the circle covered by both arcs. Two arcs together covering
+    /// the whole circle can overlap at both ends, so there may be two parts.
+    pub fn intersection(&self, other: &Arc<T>) -> [Option<Arc<T>>; 2] {
+        if self.is_full() {
+            return [Some(*other), None];
+        }
+
+        if other.is_full() {
+            return [Some(*self), None];
+        }
+
+        let start = self.offset(other.start);
+        let end = start + other.span;
+
+        // other arc as seen from self start, and once more a turn earlier
+        let part = |start: T, end: T| {
+            let start = if start > T::ZERO { start } else { T::ZERO };
+            let end = if end < self.span { end } else { self.span };
+
+            if start <= end {
+                Some(Self::from_start_span(
+                    self.start + Angle::radians(start),
+                    end - start,
+                ))
+            } else {
+                None
+            }
+        };
+
+        match (part(start, end), part(start - T::TAU, end - T::TAU)) {
+            (None, second) => [second, None],
+            parts => [parts.0, parts.1],
+        }
+    }
+
+    /// Parts of the circle covered by either arc, a single arc if they
+    /// overlap or touch.
+    pub fn union(&self, other: &Arc<T>) -> [Option<Arc<T>>; 2] {
+        if self.is_full() || other.is_full() {
+            return [Some(Self::full()), None];
+        }
+
+        // ends computed from different arcs rarely match exactly, so touching
+        // is checked with a tolerance, the same way from either side
+        let tolerance = Self::tolerance();
+
+        let extend = |arc: &Arc<T>, other: &Arc<T>| {
+            let start = arc.offset(other.start);
+
+            if start > arc.span + tolerance {
+                return None;
+            }
+
+            // spans past a full turn are clamped by from_start_span
+            let end = start + other.span;
+            let end = if end > arc.span { end } else { arc.span };
+
+            Some(Self::from_start_span(arc.start, end))
+        };
+
+        let merged = extend(self, other).or_else(|| extend(other, self));
+
+        match merged {
+            Some(arc) => [Some(arc), None],
+            None => [Some(*self), Some(*other)],
+        }
+    }
+
+    /// The rest of the circle, `None` for the full circle. Ends are shared
+    /// with `self`, as both arcs are closed.
+    pub fn complement(&self) -> Option<Arc<T>> {
+        if self.is_full() {
+            None
+        } else {
+            Some(Self::from_start_span(self.end(), T::TAU - self.span))
+        }
+    }
+
+    /// Returns `angle` if it lies within the arc, otherwise the nearer end.
+    pub fn clamp(&self, angle: Angle<T>) -> Angle<T> {
+        if self.contains(angle) {
+            return angle;
+        }
+
+        let to_start = angle.shortest_to(&self.start).abs();
+        let to_end = angle.shortest_to(&self.end()).abs();
+
+        if to_start <= to_end {
+            self.start
+        } else {
+            self.end()
+        }
+    }
+
+    /// Angles from start to end (inclusive, if it is hit exactly) spaced by
+    /// `step` radians. Empty for non-positive `step`.
+    pub fn steps(&self, step: Radians<T>) -> ArcSteps<T> {
+        ArcSteps {
+            arc: *self,
+            step,
+            index: 0,
+        }
+    }
+
+    /// Few ulps of a full turn, arcs closer than this are treated as touching.
+    pub(crate) fn tolerance() -> Radians<T> {
+        T::from_f64(4.0) * T::EPSILON * T::TAU
+    }
+
+    /// Counter-clockwise distance from start to `angle` in `[0, 2pi)`.
+    fn offset(&self, angle: Angle<T>) -> T {
+        self.start.ccw_distance(&angle).as_radians()
+    }
+}
+
+impl<T: Scalar> PartialEq for Arc<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.start == other.start && self.span == other.span
+    }
+}
+
+/// Iterator returned by [`Arc::steps`].
+#[derive(Clone, Debug)]
+pub struct ArcSteps<T = f64> {
+    arc: Arc<T>,
+    step: T,
+    index: u32,
+}
+
+impl<T: Scalar> Iterator for ArcSteps<T> {
+    type Item = Angle<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.step.is_nan() || self.step <= T::ZERO {
+            return None;
+        }
+
+        // multiplying instead of accumulating keeps rounding errors from adding up
+        let offset = T::from_f64(self.index as f64) * self.step;
+
+        if offset > self.arc.span {
+            return None;
+        }
+
+        self.index += 1;
+
+        Some(self.arc.start + Angle::radians(offset))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub(crate) fn degrees(arc: Option<Arc>) -> Option<(f64, f64)> {
+        arc.map(|arc| {
+            (
+                libm::round(arc.start().as_degrees() * 1000.0) / 1000.0,
+                libm::round(arc.span().to_degrees() * 1000.0) / 1000.0,
+            )
+        })
+    }
+
+    pub(crate) fn arc(start: f64, span: f64) -> Arc {
+        Arc::from_start_span(Angle::degrees(start), span.to_radians())
+    }
+
+    #[test]
+    fn constructors() {
+        let a = Arc::from_center(Angle::degrees(180.0), 20f64.to_radians());
+
+        assert_eq!(degrees(Some(a)), Some((160.0, 40.0)));
+        assert_eq!(
+            degrees(Some(Arc::between(
+                Angle::degrees(170.0),
+                Angle::degrees(-170.0)
+            ))),
+            Some((170.0, 20.0))
+        );
+        assert!(a
+            .end()
+            .is_within(&Angle::degrees(-160.0), Angle::degrees(0.001)));
+        assert!(a
+            .center()
+            .is_within(&Angle::degrees(180.0), Angle::degrees(0.001)));
+        assert!(arc(0.0, 400.0).is_full());
+        assert_eq!(arc(0.0, -10.0).span(), 0.0);
+    }
+
+    #[test]
+    fn contains() {
+        let a = arc(160.0, 40.0);
+
+        assert!(a.contains(Angle::degrees(180.0)));
+        assert!(a.contains(Angle::degrees(-170.0)));
+        assert!(a.contains(Angle::degrees(160.0)));
+        assert!(!a.contains(Angle::degrees(0.0)));
+        assert!(!a.contains(Angle::degrees(-150.0)));
+        assert!(Arc::<f32>::full().contains(Angle::degrees(-150.0)));
+        assert!(arc(10.0, 0.0).contains(Angle::degrees(10.0)));
+    }
+
+    #[test]
+    fn intersection() {
+        let [first, second] = arc(160.0, 40.0).intersection(&arc(-170.0, 30.0));
+        assert_eq!(degrees(first), Some((-170.0, 10.0)));
+        assert_eq!(degrees(second), None);
+
+        let [first, second] = arc(-170.0, 30.0).intersection(&arc(160.0, 40.0));
+        assert_eq!(degrees(first), Some((-170.0, 10.0)));
+        assert_eq!(degrees(second), None);
+
+        let [first, second] = arc(0.0, 300.0).intersection(&arc(270.0, 120.0));
+        assert_eq!(degrees(first), Some((-90.0, 30.0)));
+        assert_eq!(degrees(second), Some((0.0, 30.0)));
+
+        assert_eq!(arc(0.0, 10.0).intersection(&arc(20.0, 10.0)), [None, None]);
+        assert_eq!(
+            degrees(arc(0.0, 10.0).intersection(&Arc::full())[0]),
+            Some((0.0, 10.0))
+        );
+    }
+
+    #[test]
+    fn union() {
+        let [first, second] = arc(160.0, 40.0).union(&arc(-170.0, 30.0));
+        assert_eq!(degrees(first), Some((160.0, 60.0)));
+        assert_eq!(degrees(second), None);
+
+        let [first, second] = arc(-170.0, 30.0).union(&arc(160.0, 40.0));
+        assert_eq!(degrees(first), Some((160.0, 60.0)));
+        assert_eq!(degrees(second), None);
+
+        let [first, second] = arc(0.0, 10.0).union(&arc(20.0, 10.0));
+        assert_eq!(degrees(first), Some((0.0, 10.0)));
+        assert_eq!(degrees(second), Some((20.0, 10.0)));
+
+        assert!(arc(0.0, 300.0).union(&arc(270.0, 120.0))[0]
+            .unwrap()
+            .is_full());
+        assert_eq!(
+            degrees(arc(0.0, 10.0).union(&arc(10.0, 10.0))[0]),
+            Some((0.0, 20.0))
+        );
+    }
+
+    #[test]
+    fn union_touching() {
+        for (a, b) in [
+            (arc(-69.0, 69.0), arc(0.0, 80.0)),
+            (arc(0.0, 80.0), arc(-69.0, 69.0)),
+        ] {
+            let [first, second] = a.union(&b);
+            assert_eq!(degrees(first), Some((-69.0, 149.0)));
+            assert_eq!(second, None);
+        }
+
+        for start in -180..180 {
+            for span in [1.0, 33.3, 69.0, 170.0] {
+                let a = arc(start as f64, span);
+                let b = Arc::from_start_span(a.end(), 0.7);
+
+                assert!(a.union(&b)[1].is_none());
+                assert!(b.union(&a)[1].is_none());
+                assert_eq!(degrees(a.union(&b)[0]), degrees(b.union(&a)[0]));
+            }
+        }
+    }
+
+    #[test]
+    fn complement() {
+        assert_eq!(
+            degrees(arc(160.0, 40.0).complement()),
+            Some((-160.0, 320.0))
+        );
+        assert_eq!(Arc::<f64>::full().complement(), None);
+        assert!(arc(10.0, 0.0).complement().unwrap().is_full());
+    }
+
+    #[test]
+    fn clamp() {
+        let a = arc(160.0, 40.0);
+
+        assert_eq!(a.clamp(Angle::degrees(175.0)), Angle::degrees(175.0));
+        assert_eq!(a.clamp(Angle::degrees(90.0)), a.start());
+        assert_eq!(a.clamp(Angle::degrees(-90.0)), a.end());
+    }
+
+    #[test]
+    fn steps() {
+        let mut steps = arc(170.0, 20.0).steps(10f64.to_radians());
+
+        for expected in [170.0, 180.0, -170.0] {
+            assert!(steps
+                .next()
+                .unwrap()
+                .is_within(&Angle::degrees(expected), Angle::degrees(0.001)));
+        }
+
+        assert!(steps.next().is_none());
+        assert_eq!(arc(0.0, 20.0).steps(0.0).count(), 0);
+        assert_eq!(
+            Arc::<f64>::full()
+                .steps(core::f64::consts::FRAC_PI_2)
+                .count(),
+            5
+        );
+    }
+}
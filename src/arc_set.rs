@@ -0,0 +1,259 @@
+use crate::{Angle, Arc, Radians, Scalar};
+
+/// Set of up to `N` disjoint arcs, e.g. blind spots of a sensor.
+///
+/// Overlapping or touching arcs are merged on insertion, so the stored arcs
+/// never share a point and are kept ordered by their start.
+#[derive(Copy, Clone, Debug)]
+pub struct ArcSet<const N: usize, T = f64> {
+    arcs: [Arc<T>; N],
+    len: usize,
+}
+
+impl<const N: usize, T: Scalar> Default for ArcSet<N, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, T: Scalar> ArcSet<N, T> {
+    pub fn new() -> Self {
+        Self {
+            arcs: [Arc::full(); N],
+            len: 0,
+        }
+    }
+
+    /// Adds `arc`, merging it with the stored arcs it overlaps or touches.
+    /// Gives `arc` back if it would need another slot and the set is full.
+    pub fn insert(&mut self, arc: Arc<T>) -> Result<(), Arc<T>> {
+        let merges = |stored: &Arc<T>| stored.union(&arc)[1].is_none();
+
+        if self.len == N && !self.iter().any(merges) {
+            return Err(arc);
+        }
+
+        let mut merged = arc;
+
+        // merged arc grows and may reach arcs skipped before, so repeat until
+        // nothing changes
+        while let Some(index) = self
+            .iter()
+            .position(|stored| stored.union(&merged)[1].is_none())
+        {
+            merged = self.arcs[index].union(&merged)[0].unwrap_or(merged);
+            self.remove(index);
+        }
+
+        self.arcs[self.len] = merged;
+        self.len += 1;
+        self.arcs[..self.len].sort_unstable_by(|a, b| a.start().total_cmp(&b.start()));
+
+        Ok(())
+    }
+
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn iter(&self) -> core::slice::Iter<'_, Arc<T>> {
+        self.arcs[..self.len].iter()
+    }
+
+    pub fn contains(&self, angle: Angle<T>) -> bool {
+        self.iter().any(|arc| arc.contains(angle))
+    }
+
+    /// Total angle covered by the arcs, within `[0, 2pi]`.
+    pub fn covered(&self) -> Radians<T> {
+        self.iter().fold(T::ZERO, |sum, arc| sum + arc.span())
+    }
+
+    /// Largest uncovered arc, `None` if the whole circle is covered. Ends of
+    /// the returned arc belong to the neighbouring arcs of the set.
+    pub fn largest_gap(&self) -> Option<Arc<T>> {
+        if self.is_empty() {
+            return Some(Arc::full());
+        }
+
+        let arcs = &self.arcs[..self.len];
+
+        if let [arc] = arcs {
+            return arc.complement();
+        }
+
+        (0..arcs.len())
+            .map(|i| Arc::between(arcs[i].end(), arcs[(i + 1) % arcs.len()].start()))
+            .max_by(|a, b| a.span().total_cmp(&b.span()))
+    }
+
+    /// `angle` itself if it is not covered, otherwise the uncovered angle
+    /// just past the nearer end of the arc covering it. `None` if the whole
+    /// circle is covered.
+    pub fn nearest_uncovered(&self, angle: Angle<T>) -> Option<Angle<T>> {
+        let arcs = &self.arcs[..self.len];
+
+        let Some(index) = arcs.iter().position(|arc| arc.contains(angle)) else {
+            return Some(angle);
+        };
+
+        let arc = arcs[index];
+
+        if arc.is_full() {
+            return None;
+        }
+
+        // gaps are wider than the tolerance as touching arcs get merged, so
+        // stepping that far past the end leaves the arc without reaching the
+        // next one
+        let step = Angle::radians(Arc::tolerance());
+
+        let to_start = angle.shortest_to(&arc.start()).abs();
+        let to_end = angle.shortest_to(&arc.end()).abs();
+
+        Some(if to_start <= to_end {
+            arc.start() - step
+        } else {
+            arc.end() + step
+        })
+    }
+
+    fn remove(&mut self, index: usize) {
+        self.arcs.copy_within(index + 1..self.len, index);
+        self.len -= 1;
+    }
+}
+
+impl<'a, const N: usize, T: Scalar> IntoIterator for &'a ArcSet<N, T> {
+    type Item = &'a Arc<T>;
+    type IntoIter = core::slice::Iter<'a, Arc<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arc::tests::{arc, degrees};
+
+    #[test]
+    fn merge() {
+        let mut set = ArcSet::<3>::new();
+
+        set.insert(arc(10.0, 20.0)).unwrap();
+        set.insert(arc(170.0, 20.0)).unwrap();
+        set.insert(arc(-90.0, 10.0)).unwrap();
+        assert_eq!(set.insert(arc(60.0, 10.0)), Err(arc(60.0, 10.0)));
+
+        // bridges the first two arcs
+        set.insert(arc(25.0, 150.0)).unwrap();
+
+        assert_eq!(set.len(), 2);
+        assert_eq!(degrees(Some(set.arcs[0])), Some((-90.0, 10.0)));
+        assert_eq!(degrees(Some(set.arcs[1])), Some((10.0, 180.0)));
+        assert!((set.covered().to_degrees() - 190.0).abs() < 1e-9);
+
+        set.insert(arc(-100.0, 300.0)).unwrap();
+        assert_eq!(set.len(), 1);
+        assert_eq!(degrees(Some(set.arcs[0])), Some((-100.0, 300.0)));
+
+        set.insert(arc(190.0, 70.0)).unwrap();
+        assert!(set.iter().next().unwrap().is_full());
+    }
+
+    #[test]
+    fn merge_touching() {
+        let mut set = ArcSet::<2>::new();
+
+        set.insert(arc(-69.0, 69.0)).unwrap();
+        set.insert(arc(0.0, 80.0)).unwrap();
+        assert_eq!(set.len(), 1);
+
+        set.insert(arc(120.0, 10.0)).unwrap();
+        set.insert(arc(130.0, 20.0)).unwrap();
+        assert_eq!(set.len(), 2);
+        assert_eq!(degrees(set.largest_gap()), Some((150.0, 141.0)));
+    }
+
+    #[test]
+    fn contains() {
+        let mut set = ArcSet::<4>::default();
+
+        assert!(!set.contains(Angle::ZERO));
+
+        set.insert(arc(170.0, 20.0)).unwrap();
+        set.insert(arc(-10.0, 20.0)).unwrap();
+
+        assert!(set.contains(Angle::degrees(180.0)));
+        assert!(set.contains(Angle::degrees(5.0)));
+        assert!(!set.contains(Angle::degrees(90.0)));
+        assert_eq!((&set).into_iter().count(), 2);
+
+        set.clear();
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn gaps() {
+        let mut set = ArcSet::<4>::new();
+
+        assert!(set.largest_gap().unwrap().is_full());
+
+        set.insert(arc(170.0, 20.0)).unwrap();
+        assert_eq!(degrees(set.largest_gap()), Some((-170.0, 340.0)));
+
+        set.insert(arc(-10.0, 20.0)).unwrap();
+        set.insert(arc(90.0, 10.0)).unwrap();
+        assert_eq!(degrees(set.largest_gap()), Some((-170.0, 160.0)));
+
+        set.insert(arc(0.0, 360.0)).unwrap();
+        assert_eq!(set.largest_gap(), None);
+    }
+
+    #[test]
+    fn nearest_uncovered() {
+        let mut set = ArcSet::<3>::new();
+
+        set.insert(arc(170.0, 30.0)).unwrap();
+
+        assert_eq!(
+            set.nearest_uncovered(Angle::degrees(90.0)),
+            Some(Angle::degrees(90.0))
+        );
+
+        let nearest = set.nearest_uncovered(Angle::degrees(175.0)).unwrap();
+        assert!(!set.contains(nearest));
+        assert!(nearest.is_within(&Angle::degrees(170.0), Angle::degrees(0.001)));
+
+        let nearest = set.nearest_uncovered(Angle::degrees(-170.0)).unwrap();
+        assert!(!set.contains(nearest));
+        assert!(nearest.is_within(&Angle::degrees(-160.0), Angle::degrees(0.001)));
+
+        // narrow gap after the first arc is not crossed
+        let end = arc(170.0, 30.0).end();
+        set.insert(Arc::between(
+            end + Angle::radians(1e-13),
+            Angle::degrees(169.0),
+        ))
+        .unwrap();
+        assert_eq!(set.len(), 2);
+
+        for degrees in [-161.0, -159.0, 169.5] {
+            let nearest = set.nearest_uncovered(Angle::degrees(degrees)).unwrap();
+            assert!(!set.contains(nearest));
+        }
+
+        set.insert(Arc::full()).unwrap();
+        assert_eq!(set.nearest_uncovered(Angle::degrees(90.0)), None);
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{Angle, Degrees, Radians, Scalar, UnsignedAngle};
+use core::{
+    fmt::{Display, Formatter},
+    ops::{Add, AddAssign, Sub, SubAssign},
+};
+
+/// Compass bearing, measured clockwise from north in `[0, 2pi)`.
+///
+/// Unlike [`Angle`], which goes counter-clockwise from the positive X axis
+/// (east), bearings follow navigation convention. The two only convert with
+/// [`Bearing::from_angle`] and [`Bearing::to_angle`], and there is
+/// deliberately no arithmetic mixing them, so adding a heading to a bearing
+/// does not compile.
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct Bearing<T = f64> {
+    angle: UnsignedAngle<T>,
+}
+
+impl<T: Scalar> Bearing<T> {
+    pub fn radians(value: Radians<T>) -> Self {
+        Self {
+            angle: UnsignedAngle::radians(value),
+        }
+    }
+
+    pub fn degrees(value: Degrees<T>) -> Self {
+        Self {
+            angle: UnsignedAngle::degrees(value),
+        }
+    }
+
+    /// Converts from math convention, e.g. `Angle::degrees(0.0)` (east) gives
+    /// bearing of `90deg`.
+    pub fn from_angle(angle: Angle<T>) -> Self {
+        Self {
+            angle: UnsignedAngle::from(Angle::QUARTER_TURN - angle),
+        }
+    }
+
+    /// Converts to math convention, e.g. bearing of `90deg` (east) gives
+    /// `Angle::degrees(0.0)`.
+    pub fn to_angle(&self) -> Angle<T> {
+        Angle::QUARTER_TURN - Angle::from(self.angle)
+    }
+
+    pub fn as_radians(&self) -> Radians<T> {
+        self.angle.as_radians()
+    }
+
+    pub fn as_degrees(&self) -> Degrees<T> {
+        self.angle.as_degrees()
+    }
+
+    pub fn is_within(&self, other: &Bearing<T>, difference: Angle<T>) -> bool {
+        self.angle.is_within(&other.angle, difference)
+    }
+}
+
+impl<T: Scalar> Add for Bearing<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            angle: self.angle + rhs.angle,
+        }
+    }
+}
+
+impl<T: Scalar> Sub for Bearing<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            angle: self.angle - rhs.angle,
+        }
+    }
+}
+
+impl<T: Scalar> AddAssign for Bearing<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Scalar> SubAssign for Bearing<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Scalar + Display> Display for Bearing<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.angle.fmt(f)
+    }
+}
+
+#[cfg(feature = "use_defmt")]
+impl<T: Scalar> defmt::Format for Bearing<T> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{=f32}deg", self.as_degrees().to_f32());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use std::format;
+
+    #[test]
+    fn conversions() {
+        let cases = [(0.0, 90.0), (90.0, 0.0), (-90.0, 180.0), (180.0, 270.0)];
+
+        for (angle, bearing) in cases {
+            let converted = Bearing::from_angle(Angle::degrees(angle));
+
+            assert!(converted.is_within(&Bearing::degrees(bearing), Angle::degrees(0.001)));
+            assert!(converted
+                .to_angle()
+                .is_within(&Angle::degrees(angle), Angle::degrees(0.001)));
+        }
+
+        assert!(Bearing::<f32>::degrees(-45.0)
+            .to_angle()
+            .is_within(&Angle::degrees(135.0), Angle::degrees(0.001)));
+    }
+
+    #[test]
+    fn arithmetic() {
+        let mut b = Bearing::degrees(350.0);
+
+        assert!(
+            (b + Bearing::degrees(20.0)).is_within(&Bearing::degrees(10.0), Angle::degrees(0.001))
+        );
+
+        b -= Bearing::degrees(355.0);
+        assert!((b.as_degrees() - 355.0).abs() < 0.001);
+
+        b += Bearing::degrees(10.0);
+        assert!((b.as_degrees() - 5.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{:.1}", Bearing::degrees(-90.0)), "270.0deg");
+        assert_eq!(format!("{:.0}", Bearing::<f32>::degrees(45.0)), "45deg");
+        assert_eq!(format!("{}", Bearing::degrees(0.1)), "0.1deg");
+        assert_eq!(format!("{}", Bearing::degrees(10.0)), "10deg");
+        assert_eq!(format!("{}", Bearing::degrees(247.5)), "247.5deg");
+
+        for i in 0..100_000 {
+            let bearing = Bearing::degrees(i as f64 * 0.0036000000123);
+
+            assert_eq!(
+                format!("{}", bearing),
+                format!("{}deg", bearing.as_degrees())
+            );
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use crate::{Angle, Scalar};
+use core::{
+    f64::consts::TAU,
+    ops::{Add, Mul, Neg, Sub},
+};
+use libm::round;
+
+/// Angle stored as binary angle measurement (BAM).
+///
+/// The full circle is mapped onto the whole range of the underlying unsigned
+/// integer (`u16` or `u32`), so wrapping integer arithmetic is the
+/// normalization and no floating point math is ever needed. `sin` and `cos`
+/// are computed with CORDIC and returned as signed fixed-point numbers
+/// (Q15 for `u16`, Q31 for `u32`).
+///
+/// With `use_defmt` feature, `BinaryAngle<u16>` is the cheapest way to log an
+/// angle: only two bytes are sent, e.g. `BinaryAngle::<u16>::from(heading)`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BinaryAngle<T = u32> {
+    value: T,
+}
+
+/// `atan(2^-i)` expressed in 32-bit BAM units.
+const CORDIC_ATAN: [i64; 31] = [
+    0x20000000, 0x12e4051e, 0x09fb385b, 0x051111d4, 0x028b0d43, 0x0145d7e1, 0x00a2f61e, 0x00517c55,
+    0x0028be53, 0x00145f2f, 0x000a2f98, 0x000517cc, 0x00028be6, 0x000145f3, 0x0000a2fa, 0x0000517d,
+    0x000028be, 0x0000145f, 0x00000a30, 0x00000518, 0x0000028c, 0x00000146, 0x000000a3, 0x00000051,
+    0x00000029, 0x00000014, 0x0000000a, 0x00000005, 0x00000003, 0x00000001, 0x00000001,
+];
+
+/// Inverse of the CORDIC gain in Q30.
+const CORDIC_GAIN: i64 = 652032874;
+
+const QUARTER_TURN: i64 = 1 << 30;
+
+/// Returns `(cos, sin)` in Q30 for angle given in 32-bit BAM units.
+fn cordic(angle: u32) -> (i64, i64) {
+    let mut z = angle as i32 as i64;
+
+    // CORDIC converges only within +-90deg, rotate remaining half by 180deg
+    let flip = !(-QUARTER_TURN..=QUARTER_TURN).contains(&z);
+    if flip {
+        z = angle.wrapping_add(1 << 31) as i32 as i64;
+    }
+
+    let mut x = CORDIC_GAIN;
+    let mut y = 0;
+
+    for (i, step) in CORDIC_ATAN.iter().enumerate() {
+        let (dx, dy) = (y >> i, x >> i);
+
+        if z >= 0 {
+            x -= dx;
+            y += dy;
+            z -= step;
+        } else {
+            x += dx;
+            y -= dy;
+            z += step;
+        }
+    }
+
+    if flip {
+        (-x, -y)
+    } else {
+        (x, y)
+    }
+}
+
+macro_rules! impl_binary_angle {
+    ($t:ty, $fixed:ty, $bits:expr, $defmt:literal, $to_bam32:expr, $from_q30:expr) => {
+        impl BinaryAngle<$t> {
+            pub const fn from_raw(value: $t) -> Self {
+                Self { value }
+            }
+
+            pub const fn as_raw(&self) -> $t {
+                self.value
+            }
+
+            /// Cosine as fixed-point number with
+            #[doc = concat!(stringify!($bits), " fractional bits.")]
+            pub fn cos(&self) -> $fixed {
+                self.sin_cos().1
+            }
+
+            /// Sine as fixed-point number with
+            #[doc = concat!(stringify!($bits), " fractional bits.")]
+            pub fn sin(&self) -> $fixed {
+                self.sin_cos().0
+            }
+
+            /// Sine and cosine computed together in one CORDIC pass.
+            pub fn sin_cos(&self) -> ($fixed, $fixed) {
+                let (cos, sin) = cordic($to_bam32(self.value));
+
+                ($from_q30(sin), $from_q30(cos))
+            }
+        }
+
+        /// Sends only the raw value, written as fraction of a full turn.
+        #[cfg(feature = "use_defmt")]
+        impl defmt::Format for BinaryAngle<$t> {
+            fn format(&self, f: defmt::Formatter) {
+                defmt::write!(f, $defmt, self.value);
+            }
+        }
+
+        impl Add for BinaryAngle<$t> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                Self {
+                    value: self.value.wrapping_add(rhs.value),
+                }
+            }
+        }
+
+        impl Sub for BinaryAngle<$t> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self {
+                    value: self.value.wrapping_sub(rhs.value),
+                }
+            }
+        }
+
+        impl Neg for BinaryAngle<$t> {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                Self {
+                    value: self.value.wrapping_neg(),
+                }
+            }
+        }
+
+        impl Mul<$t> for BinaryAngle<$t> {
+            type Output = Self;
+
+            fn mul(self, rhs: $t) -> Self::Output {
+                Self {
+                    value: self.value.wrapping_mul(rhs),
+                }
+            }
+        }
+
+        impl<T: Scalar> From<BinaryAngle<$t>> for Angle<T> {
+            fn from(angle: BinaryAngle<$t>) -> Self {
+                let turns = angle.value as f64 / (1u64 << <$t>::BITS) as f64;
+
+                Angle::radians(T::from_f64(turns * TAU))
+            }
+        }
+
+        impl<T: Scalar> From<Angle<T>> for BinaryAngle<$t> {
+            fn from(angle: Angle<T>) -> Self {
+                let turns = angle.as_radians().to_f64() / TAU;
+                let value = round(turns * (1u64 << <$t>::BITS) as f64) as i64;
+
+                Self { value: value as $t }
+            }
+        }
+    };
+}
+
+fn q30_to_q15(value: i64) -> i16 {
+    (value >> 15).clamp(i16::MIN as i64, i16::MAX as i64) as i16
+}
+
+fn q30_to_q31(value: i64) -> i32 {
+    (value << 1).clamp(i32::MIN as i64, i32::MAX as i64) as i32
+}
+
+impl_binary_angle!(
+    u16,
+    i16,
+    15,
+    "{=u16}/65536turn",
+    |value: u16| (value as u32) << 16,
+    q30_to_q15
+);
+impl_binary_angle!(
+    u32,
+    i32,
+    31,
+    "{=u32}/4294967296turn",
+    |value: u32| value,
+    q30_to_q31
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrapping() {
+        let a1 = BinaryAngle::<u16>::from_raw(0xc000);
+        let a2 = BinaryAngle::<u16>::from_raw(0x8000);
+
+        assert_eq!((a1 + a2).as_raw(), 0x4000);
+        assert_eq!((a2 - a1).as_raw(), 0xc000);
+        assert_eq!((-a1).as_raw(), 0x4000);
+        assert_eq!((a1 * 3).as_raw(), 0x4000);
+    }
+
+    #[test]
+    fn to_angle() {
+        let a1: Angle = BinaryAngle::<u32>::from_raw(0x4000_0000).into();
+        let a2: Angle = BinaryAngle::<u16>::from_raw(0xc000).into();
+        let a3: Angle<f32> = BinaryAngle::<u16>::from_raw(0x8000).into();
+
+        assert!(a1.is_within(&Angle::degrees(90.0), Angle::degrees(0.001)));
+        assert!(a2.is_within(&Angle::degrees(-90.0), Angle::degrees(0.001)));
+        assert!(a3.is_within(&Angle::degrees(180.0), Angle::degrees(0.001)));
+    }
+
+    #[test]
+    fn round_trip() {
+        for raw in [0, 1, 0x1234_5678, 0x7fff_ffff, 0x8000_0000, 0xffff_ffff] {
+            let angle: Angle = BinaryAngle::<u32>::from_raw(raw).into();
+
+            assert_eq!(BinaryAngle::<u32>::from(angle).as_raw(), raw);
+        }
+
+        for raw in [0, 1, 0x1234, 0x7fff, 0x8000, 0xffff] {
+            let angle: Angle<f32> = BinaryAngle::<u16>::from_raw(raw).into();
+
+            assert_eq!(BinaryAngle::<u16>::from(angle).as_raw(), raw);
+        }
+    }
+
+    #[test]
+    fn sin_cos() {
+        for degrees in [
+            -180.0, -135.0, -90.0, -30.0, 0.0, 15.0, 45.0, 90.0, 120.0, 179.0,
+        ] {
+            let angle = Angle::degrees(degrees);
+            let b32 = BinaryAngle::<u32>::from(angle);
+            let b16 = BinaryAngle::<u16>::from(angle);
+
+            let scale32 = (1u64 << 31) as f64;
+            assert!((b32.sin() as f64 / scale32 - angle.sin()).abs() < 1e-8);
+            assert!((b32.cos() as f64 / scale32 - angle.cos()).abs() < 1e-8);
+
+            let scale16 = (1u32 << 15) as f64;
+            assert!((b16.sin() as f64 / scale16 - angle.sin()).abs() < 1e-4);
+            assert!((b16.cos() as f64 / scale16 - angle.cos()).abs() < 1e-4);
+        }
+    }
+}
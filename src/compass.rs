@@ -0,0 +1,340 @@
+//! Compass point names.
+//!
+//! [`CompassPoint`] covers the 32-point compass rose, coarser roses use
+//! a subset of its points selected with [`Points`]. Names are written the
+//! usual way, e.g. `NNE` or `SW by W`.
+
+use crate::{Angle, Bearing, Scalar};
+use core::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+use libm::{ceil, fabs, floor, round};
+
+/// Number of points on the compass rose.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "use_defmt", derive(defmt::Format))]
+pub enum Points {
+    /// Cardinal points, `N`, `E`, `S` and `W`.
+    Four,
+    /// Adds intercardinal points, e.g. `NE`.
+    Eight,
+    /// Adds secondary intercardinal points, e.g. `NNE`.
+    Sixteen,
+    /// Adds by-points, e.g. `N by E`.
+    ThirtyTwo,
+}
+
+impl Points {
+    pub fn count(&self) -> usize {
+        match self {
+            Points::Four => 4,
+            Points::Eight => 8,
+            Points::Sixteen => 16,
+            Points::ThirtyTwo => 32,
+        }
+    }
+}
+
+/// Selects the compass point for a bearing between two points.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "use_defmt", derive(defmt::Format))]
+pub enum Rounding {
+    /// Nearest point, so each sector is centered on its point.
+    Nearest,
+    /// Last point passed going clockwise, so each sector starts at its point.
+    Down,
+    /// Next point going clockwise, so each sector ends at its point.
+    Up,
+}
+
+/// Point of the 32-point compass rose, in clockwise order from north.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "use_defmt", derive(defmt::Format))]
+pub enum CompassPoint {
+    N,
+    NbE,
+    NNE,
+    NEbN,
+    NE,
+    NEbE,
+    ENE,
+    EbN,
+    E,
+    EbS,
+    ESE,
+    SEbE,
+    SE,
+    SEbS,
+    SSE,
+    SbE,
+    S,
+    SbW,
+    SSW,
+    SWbS,
+    SW,
+    SWbW,
+    WSW,
+    WbS,
+    W,
+    WbN,
+    WNW,
+    NWbW,
+    NW,
+    NWbN,
+    NNW,
+    NbW,
+}
+
+impl CompassPoint {
+    /// All points in clockwise order from north.
+    pub const ALL: [CompassPoint; 32] = [
+        CompassPoint::N,
+        CompassPoint::NbE,
+        CompassPoint::NNE,
+        CompassPoint::NEbN,
+        CompassPoint::NE,
+        CompassPoint::NEbE,
+        CompassPoint::ENE,
+        CompassPoint::EbN,
+        CompassPoint::E,
+        CompassPoint::EbS,
+        CompassPoint::ESE,
+        CompassPoint::SEbE,
+        CompassPoint::SE,
+        CompassPoint::SEbS,
+        CompassPoint::SSE,
+        CompassPoint::SbE,
+        CompassPoint::S,
+        CompassPoint::SbW,
+        CompassPoint::SSW,
+        CompassPoint::SWbS,
+        CompassPoint::SW,
+        CompassPoint::SWbW,
+        CompassPoint::WSW,
+        CompassPoint::WbS,
+        CompassPoint::W,
+        CompassPoint::WbN,
+        CompassPoint::WNW,
+        CompassPoint::NWbW,
+        CompassPoint::NW,
+        CompassPoint::NWbN,
+        CompassPoint::NNW,
+        CompassPoint::NbW,
+    ];
+
+    const NAMES: [&'static str; 32] = [
+        "N", "N by E", "NNE", "NE by N", "NE", "NE by E", "ENE", "E by N", "E", "E by S", "ESE",
+        "SE by E", "SE", "SE by S", "SSE", "S by E", "S", "S by W", "SSW", "SW by S", "SW",
+        "SW by W", "WSW", "W by S", "W", "W by N", "WNW", "NW by W", "NW", "NW by N", "NNW",
+        "N by W",
+    ];
+
+    pub fn from_bearing<T: Scalar>(
+        bearing: Bearing<T>,
+        points: Points,
+        rounding: Rounding,
+    ) -> Self {
+        let count = points.count();
+        let sector = bearing.as_degrees().to_f64() / (360.0 / count as f64);
+
+        // bearings of the points themselves may come out a few ulps off, which
+        // would move them to the neighbouring sector when rounding down or up
+        let nearest = round(sector);
+        let tolerance = count as f64 * T::EPSILON.to_f64() * 8.0;
+        let sector = if fabs(sector - nearest) <= tolerance {
+            nearest
+        } else {
+            sector
+        };
+
+        let sector = match rounding {
+            Rounding::Nearest => round(sector),
+            Rounding::Down => floor(sector),
+            Rounding::Up => ceil(sector),
+        };
+
+        Self::ALL[(sector as usize % count) * (32 / count)]
+    }
+
+    /// Converts the heading from math convention first, see
+    /// [`Bearing::from_angle`].
+    pub fn from_angle<T: Scalar>(angle: Angle<T>, points: Points, rounding: Rounding) -> Self {
+        Self::from_bearing(Bearing::from_angle(angle), points, rounding)
+    }
+
+    /// Bearing of the point, i.e. center of its sector with
+    /// [`Rounding::Nearest`].
+    pub fn bearing<T: Scalar>(&self) -> Bearing<T> {
+        Bearing::degrees(T::from_f64(*self as usize as f64 * 11.25))
+    }
+
+    /// Same as [`CompassPoint::bearing`] in math convention.
+    pub fn angle<T: Scalar>(&self) -> Angle<T> {
+        self.bearing().to_angle()
+    }
+
+    /// Name such as `NNE` or `SW by W`.
+    pub fn name(&self) -> &'static str {
+        Self::NAMES[*self as usize]
+    }
+}
+
+impl Display for CompassPoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+/// Rejected compass point name.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "use_defmt", derive(defmt::Format))]
+pub struct ParseCompassPointError;
+
+impl Display for ParseCompassPointError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "unknown compass point")
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl std::error::Error for ParseCompassPointError {}
+
+/// Accepts names as written by [`CompassPoint::name`] and the compact form of
+/// by-points (`SWbW`), ignoring case and surrounding whitespace.
+impl FromStr for CompassPoint {
+    type Err = ParseCompassPointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        Self::ALL
+            .iter()
+            .find(|point| {
+                let name = point.name();
+
+                name.eq_ignore_ascii_case(s)
+                    || name
+                        .split_once(" by ")
+                        .is_some_and(|(from, to)| is_compact(s, from, to))
+            })
+            .copied()
+            .ok_or(ParseCompassPointError)
+    }
+}
+
+/// Checks if `s` is `from`, `b` and `to` joined, ignoring case.
+fn is_compact(s: &str, from: &str, to: &str) -> bool {
+    s.len() == from.len() + 1 + to.len()
+        && s.get(..from.len())
+            .is_some_and(|s| s.eq_ignore_ascii_case(from))
+        && s.get(from.len()..from.len() + 1)
+            .is_some_and(|s| s.eq_ignore_ascii_case("b"))
+        && s.get(from.len() + 1..)
+            .is_some_and(|s| s.eq_ignore_ascii_case(to))
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use std::{format, string::ToString};
+
+    #[test]
+    fn from_bearing() {
+        let point = |degrees: f64, points| {
+            CompassPoint::from_bearing(Bearing::degrees(degrees), points, Rounding::Nearest)
+        };
+
+        assert_eq!(point(0.0, Points::Four), CompassPoint::N);
+        assert_eq!(point(44.0, Points::Four), CompassPoint::N);
+        assert_eq!(point(46.0, Points::Four), CompassPoint::E);
+        assert_eq!(point(350.0, Points::Eight), CompassPoint::N);
+        assert_eq!(point(200.0, Points::Eight), CompassPoint::S);
+        assert_eq!(point(22.5, Points::Sixteen), CompassPoint::NNE);
+        assert_eq!(point(240.0, Points::ThirtyTwo), CompassPoint::SWbW);
+        assert_eq!(point(355.0, Points::ThirtyTwo), CompassPoint::N);
+    }
+
+    #[test]
+    fn rounding() {
+        let point = |degrees: f64, rounding| {
+            CompassPoint::from_bearing(Bearing::degrees(degrees), Points::Eight, rounding)
+        };
+
+        assert_eq!(point(40.0, Rounding::Nearest), CompassPoint::NE);
+        assert_eq!(point(40.0, Rounding::Down), CompassPoint::N);
+        assert_eq!(point(50.0, Rounding::Up), CompassPoint::E);
+        assert_eq!(point(350.0, Rounding::Up), CompassPoint::N);
+        assert_eq!(point(45.0, Rounding::Up), CompassPoint::NE);
+    }
+
+    #[test]
+    fn from_angle() {
+        assert_eq!(
+            CompassPoint::from_angle(
+                Angle::<f32>::degrees(0.0),
+                Points::Sixteen,
+                Rounding::Nearest
+            ),
+            CompassPoint::E
+        );
+        assert_eq!(
+            CompassPoint::from_angle(Angle::degrees(-112.5), Points::Sixteen, Rounding::Nearest),
+            CompassPoint::SSW
+        );
+    }
+
+    #[test]
+    fn center() {
+        assert!(CompassPoint::NNE
+            .bearing()
+            .is_within(&Bearing::degrees(22.5), Angle::degrees(0.001)));
+        assert!(CompassPoint::W
+            .angle::<f64>()
+            .is_within(&Angle::degrees(180.0), Angle::degrees(0.001)));
+
+        for points in [
+            Points::Four,
+            Points::Eight,
+            Points::Sixteen,
+            Points::ThirtyTwo,
+        ] {
+            let step = 32 / points.count();
+
+            for rounding in [Rounding::Nearest, Rounding::Down, Rounding::Up] {
+                for point in CompassPoint::ALL.into_iter().step_by(step) {
+                    let bearing = point.bearing::<f64>();
+                    assert_eq!(CompassPoint::from_bearing(bearing, points, rounding), point);
+
+                    let bearing = point.bearing::<f32>();
+                    assert_eq!(CompassPoint::from_bearing(bearing, points, rounding), point);
+
+                    let angle = point.angle::<f64>();
+                    assert_eq!(CompassPoint::from_angle(angle, points, rounding), point);
+
+                    let angle = point.angle::<f32>();
+                    assert_eq!(CompassPoint::from_angle(angle, points, rounding), point);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(CompassPoint::SWbW.to_string(), "SW by W");
+        assert_eq!(format!("{:>4}|", CompassPoint::NNE), " NNE|");
+
+        for point in CompassPoint::ALL {
+            assert_eq!(point.name().parse(), Ok(point));
+        }
+
+        assert_eq!(" swbw ".parse(), Ok(CompassPoint::SWbW));
+        assert_eq!("ne BY e".parse(), Ok(CompassPoint::NEbE));
+        assert_eq!("NNNE".parse::<CompassPoint>(), Err(ParseCompassPointError));
+        assert_eq!("Nb".parse::<CompassPoint>(), Err(ParseCompassPointError));
+        assert_eq!("N°E".parse::<CompassPoint>(), Err(ParseCompassPointError));
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{Angle, Scalar, UnsignedAngle};
+
+/// Direction of the shortest turn between two angles.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "use_defmt", derive(defmt::Format))]
+pub enum Direction {
+    /// Clockwise, i.e. towards decreasing angle.
+    Cw,
+    /// Counter-clockwise, i.e. towards increasing angle.
+    Ccw,
+    /// Angles are equal.
+    None,
+}
+
+impl<T: Scalar> Angle<T> {
+    /// Signed shortest rotation taking `self` to `other`, positive
+    /// counter-clockwise. Angles half a turn apart give `pi`.
+    pub fn shortest_to(&self, other: &Angle<T>) -> Angle<T> {
+        *other - *self
+    }
+
+    /// Direction of [`Angle::shortest_to`], counter-clockwise for angles half
+    /// a turn apart.
+    pub fn turn_direction(&self, other: &Angle<T>) -> Direction {
+        let difference = self.shortest_to(other).as_radians();
+
+        if difference > T::ZERO {
+            Direction::Ccw
+        } else if difference < T::ZERO {
+            Direction::Cw
+        } else {
+            Direction::None
+        }
+    }
+
+    /// Rotation taking `self` to `other` going clockwise, in `[0, 2pi)`.
+    pub fn cw_distance(&self, other: &Angle<T>) -> UnsignedAngle<T> {
+        UnsignedAngle::from(*self - *other)
+    }
+
+    /// Rotation taking `self` to `other` going counter-clockwise, in
+    /// `[0, 2pi)`.
+    pub fn ccw_distance(&self, other: &Angle<T>) -> UnsignedAngle<T> {
+        UnsignedAngle::from(*other - *self)
+    }
+
+    /// Like [`Angle::is_within`], but also accepts angles exactly
+    /// `difference` apart, so e.g. any angle is within zero difference of
+    /// itself and any two angles are within half a turn.
+    pub fn is_within_inclusive(&self, other: &Angle<T>, difference: Angle<T>) -> bool {
+        self.shortest_to(other).abs().as_radians() <= difference.as_radians()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shortest() {
+        let a = Angle::degrees(170.0);
+        let b = Angle::degrees(-170.0);
+
+        assert!(a
+            .shortest_to(&b)
+            .is_within(&Angle::degrees(20.0), Angle::degrees(0.001)));
+        assert!(b
+            .shortest_to(&a)
+            .is_within(&Angle::degrees(-20.0), Angle::degrees(0.001)));
+        assert_eq!(
+            Angle::degrees(90.0).shortest_to(&Angle::degrees(-90.0)),
+            Angle::HALF_TURN
+        );
+    }
+
+    #[test]
+    fn direction() {
+        let a = Angle::degrees(170.0);
+        let b = Angle::degrees(-170.0);
+
+        assert_eq!(a.turn_direction(&b), Direction::Ccw);
+        assert_eq!(b.turn_direction(&a), Direction::Cw);
+        assert_eq!(a.turn_direction(&a), Direction::None);
+        assert_eq!(
+            Angle::<f32>::degrees(0.0).turn_direction(&Angle::degrees(180.0)),
+            Direction::Ccw
+        );
+    }
+
+    #[test]
+    fn distances() {
+        let a = Angle::degrees(170.0);
+        let b = Angle::degrees(-170.0);
+
+        assert!((a.ccw_distance(&b).as_degrees() - 20.0).abs() < 0.001);
+        assert!((a.cw_distance(&b).as_degrees() - 340.0).abs() < 0.001);
+        assert!((b.ccw_distance(&a).as_degrees() - 340.0).abs() < 0.001);
+        assert!((b.cw_distance(&a).as_degrees() - 20.0).abs() < 0.001);
+        assert_eq!(a.cw_distance(&a).as_radians(), 0.0);
+        assert_eq!(a.ccw_distance(&a).as_radians(), 0.0);
+    }
+
+    #[test]
+    fn within_inclusive() {
+        let a = Angle::degrees(10.0);
+        let b = Angle::degrees(-20.0);
+
+        assert!(!a.is_within(&a, Angle::ZERO));
+        assert!(a.is_within_inclusive(&a, Angle::ZERO));
+        assert!(a.is_within_inclusive(&b, Angle::degrees(30.0)));
+        assert!(!a.is_within_inclusive(&b, Angle::degrees(29.999)));
+        assert!(a.is_within_inclusive(&(a + Angle::HALF_TURN), Angle::HALF_TURN));
+    }
+}
@@ -0,0 +1,321 @@
+use crate::{dms::DmsFormat, Angle, Scalar, Unit};
+use core::fmt::{Alignment, Display, Formatter, Write};
+
+/// Display adapter created by [`Angle::display`].
+///
+/// Writes the value in the selected unit followed by its suffix, honoring
+/// precision and width flags, e.g.
+/// `format!("{:>10.2}", angle.display(Unit::Radians))`.
+#[derive(Copy, Clone, Debug)]
+pub struct AngleDisplay<T = f64> {
+    angle: Angle<T>,
+    unit: Unit,
+    unsigned: bool,
+    compass: bool,
+    dms: bool,
+}
+
+impl<T: Scalar> AngleDisplay<T> {
+    /// Writes values in `[0, 360)` range instead of `(-180, 180]`.
+    pub fn unsigned(self) -> Self {
+        Self {
+            unsigned: true,
+            ..self
+        }
+    }
+
+    /// Writes compass bearing, i.e. measured clockwise from north (the
+    /// positive Y axis) in `[0, 360)` range.
+    pub fn compass(self) -> Self {
+        Self {
+            compass: true,
+            ..self
+        }
+    }
+
+    /// Writes degrees-minutes-seconds notation, with precision flag selecting
+    /// number of decimal places of seconds. Overrides the unit.
+    pub fn dms(self) -> Self {
+        Self { dms: true, ..self }
+    }
+
+    fn value(&self, unit: Unit) -> T {
+        if self.unsigned && !self.compass {
+            return self.angle.as_unsigned_unit(unit);
+        }
+
+        let value = self.angle.as_unit(unit);
+
+        // bearing is computed in the target unit, so values exact in it stay
+        // exact
+        let value = if self.compass {
+            Angle::<T>::QUARTER_TURN.as_unit(unit) - value
+        } else {
+            value
+        };
+
+        // adding zero turns -0 into 0, the same way angles compare
+        if !self.compass || value >= T::ZERO {
+            return value + T::ZERO;
+        }
+
+        let full_turn = Angle::<T>::HALF_TURN.as_unit(unit) * T::from_f64(2.0);
+        let value = value + full_turn;
+
+        // adding full turn to tiny negative value may round up to full turn
+        if value < full_turn {
+            value
+        } else {
+            T::ZERO
+        }
+    }
+}
+
+impl<T: Scalar + Display> Display for AngleDisplay<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let precision = f.precision();
+
+        if self.dms {
+            let degrees = self.value(Unit::Degrees).to_f64();
+            let precision = precision
+                .unwrap_or(0)
+                .min(DmsFormat::MAX_PRECISION as usize);
+            let dms = DmsFormat::new(degrees, precision as u8, true);
+
+            return pad(f, |w| write!(w, "{}", dms));
+        }
+
+        let value = self.value(self.unit);
+        let suffix = self.unit.suffix();
+
+        match precision {
+            Some(precision) => pad(f, |w| write!(w, "{:.*}{}", precision, value, suffix)),
+            None => pad(f, |w| write!(w, "{}{}", value, suffix)),
+        }
+    }
+}
+
+/// Sends the value as `f32` with the unit suffix kept in the interned format
+/// string, so only four bytes go over the wire. DMS output is sent as degrees.
+#[cfg(feature = "use_defmt")]
+impl<T: Scalar> defmt::Format for AngleDisplay<T> {
+    fn format(&self, f: defmt::Formatter) {
+        let unit = if self.dms { Unit::Degrees } else { self.unit };
+        let value = self.value(unit).to_f32();
+
+        match unit {
+            Unit::Radians => defmt::write!(f, "{=f32}rad", value),
+            Unit::Degrees => defmt::write!(f, "{=f32}deg", value),
+            Unit::Turns => defmt::write!(f, "{=f32}turn", value),
+            Unit::Gradians => defmt::write!(f, "{=f32}grad", value),
+            Unit::ArcMinutes => defmt::write!(f, "{=f32}arcmin", value),
+            Unit::ArcSeconds => defmt::write!(f, "{=f32}arcsec", value),
+            Unit::Mils => defmt::write!(f, "{=f32}mil", value),
+        }
+    }
+}
+
+impl<T: Scalar> Angle<T> {
+    /// Display adapter writing the angle in given unit.
+    pub fn display(&self, unit: Unit) -> AngleDisplay<T> {
+        AngleDisplay {
+            angle: *self,
+            unit,
+            unsigned: false,
+            compass: false,
+            dms: false,
+        }
+    }
+}
+
+/// Writes in degrees, see [`AngleDisplay`] for other formats.
+impl<T: Scalar + Display> Display for Angle<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.display(Unit::Degrees).fmt(f)
+    }
+}
+
+struct Counter(usize);
+
+impl Write for Counter {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Applies width, fill and alignment flags to the whole output of `body`.
+fn pad(
+    f: &mut Formatter<'_>,
+    body: impl Fn(&mut dyn Write) -> core::fmt::Result,
+) -> core::fmt::Result {
+    let Some(width) = f.width() else {
+        return body(f);
+    };
+
+    let mut counter = Counter(0);
+    body(&mut counter)?;
+
+    let padding = width.saturating_sub(counter.0);
+    let (before, after) = match f.align() {
+        Some(Alignment::Left) => (0, padding),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(Alignment::Right) | None => (padding, 0),
+    };
+
+    let fill = f.fill();
+
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+
+    body(f)?;
+
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use std::format;
+
+    #[test]
+    fn display() {
+        let a = Angle::degrees(-90.0);
+
+        assert_eq!(format!("{}", a), "-90deg");
+        assert_eq!(format!("{:.2}", a), "-90.00deg");
+        assert_eq!(format!("{:>12.1}", a), "    -90.0deg");
+        assert_eq!(format!("{:<9}|", a), "-90deg   |");
+        assert_eq!(format!("{:*^10}", a), "**-90deg**");
+        assert_eq!(format!("{:.1}", Angle::<f32>::degrees(45.0)), "45.0deg");
+    }
+
+    #[test]
+    fn units() {
+        let a = Angle::degrees(-90.0);
+
+        assert_eq!(format!("{:.4}", a.display(Unit::Radians)), "-1.5708rad");
+        assert_eq!(format!("{}", a.display(Unit::Turns)), "-0.25turn");
+        assert_eq!(format!("{}", a.display(Unit::Mils)), "-1600mil");
+        assert_eq!(
+            format!("{:.0}", a.display(Unit::Degrees).unsigned()),
+            "270deg"
+        );
+        assert_eq!(
+            format!("{}", a.display(Unit::Gradians).unsigned()),
+            "300grad"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                Angle::radians(-1e-300).display(Unit::Degrees).unsigned()
+            ),
+            "0deg"
+        );
+    }
+
+    #[test]
+    fn unsigned() {
+        use crate::UnsignedAngle;
+
+        for i in 0..100_000 {
+            let angle = Angle::degrees(-180.0 + i as f64 * 0.0036000000123);
+            let unsigned = UnsignedAngle::from(angle);
+
+            assert_eq!(
+                format!("{}", angle.display(Unit::Degrees).unsigned()),
+                format!("{}deg", unsigned.as_degrees())
+            );
+        }
+    }
+
+    #[test]
+    fn compass() {
+        assert_eq!(
+            format!(
+                "{:.0}",
+                Angle::degrees(90.0).display(Unit::Degrees).compass()
+            ),
+            "0deg"
+        );
+        assert_eq!(
+            format!(
+                "{:.0}",
+                Angle::degrees(0.0).display(Unit::Degrees).compass()
+            ),
+            "90deg"
+        );
+        assert_eq!(
+            format!(
+                "{:.0}",
+                Angle::degrees(-45.0).display(Unit::Degrees).compass()
+            ),
+            "135deg"
+        );
+        assert_eq!(
+            format!(
+                "{:.0}",
+                Angle::degrees(135.0).display(Unit::Degrees).compass()
+            ),
+            "315deg"
+        );
+        assert_eq!(
+            format!("{}", Angle::degrees(123.4).display(Unit::Degrees).compass()),
+            "326.6deg"
+        );
+        assert_eq!(
+            format!("{}", Angle::degrees(90.0).display(Unit::Degrees).compass()),
+            "0deg"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                Angle::degrees(-45.0).display(Unit::Gradians).compass()
+            ),
+            "150grad"
+        );
+    }
+
+    #[test]
+    fn negative_zero() {
+        assert_eq!(format!("{}", Angle::degrees(-0.0)), "0deg");
+        assert_eq!(format!("{:.1}", Angle::<f32>::radians(-0.0)), "0.0deg");
+        assert_eq!(
+            format!("{}", Angle::radians(-0.0).display(Unit::Turns).unsigned()),
+            "0turn"
+        );
+        assert_eq!(
+            format!("{:.1}", Angle::degrees(-0.0).display(Unit::Degrees).dms()),
+            "0°00'00.0\""
+        );
+    }
+
+    #[test]
+    fn dms() {
+        let a = Angle::arcseconds(-(122.0 * 3600.0 + 19.0 * 60.0 + 55.1));
+
+        assert_eq!(
+            format!("{:.1}", a.display(Unit::Degrees).dms()),
+            "-122°19'55.1\""
+        );
+        assert_eq!(
+            format!("{:>14}", a.display(Unit::Degrees).dms()),
+            "   -122°19'55\""
+        );
+        assert_eq!(
+            format!("{:.1}", a.display(Unit::Degrees).dms().unsigned()),
+            "237°40'04.9\""
+        );
+        assert_eq!(
+            format!("{:.256}", Angle::degrees(0.5).display(Unit::Degrees).dms()),
+            "0°30'00.000000000\""
+        );
+    }
+}
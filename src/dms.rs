@@ -0,0 +1,368 @@
+//! Degrees-minutes-seconds notation.
+//!
+//! [`parse`] reads the usual notations of geographic coordinates, e.g.
+//! `47°36'22.5"N`, `-122 19 55.1`, `47 36.375` or `47.6062`, while
+//! [`Angle::dms`] and [`Angle::dm`] format angles the same way.
+
+use crate::{Angle, Scalar};
+use core::fmt::{Display, Formatter};
+use libm::round;
+
+/// Reason for rejecting a degrees-minutes-seconds string.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "use_defmt", derive(defmt::Format))]
+pub enum DmsError {
+    /// No numeric component found.
+    Empty,
+    /// Component is not a valid decimal number.
+    InvalidNumber,
+    /// Character that is neither part of a number, a separator nor
+    /// a hemisphere letter, or a unit marker out of order.
+    UnexpectedCharacter(char),
+    /// More than degrees, minutes and seconds given.
+    TooManyComponents,
+    /// Only the last component can have a fractional part.
+    FractionalComponent,
+    /// Minutes or seconds not below 60.
+    ComponentOutOfRange,
+    /// Both a sign and a hemisphere letter given.
+    ConflictingSign,
+}
+
+impl Display for DmsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DmsError::Empty => write!(f, "no angle value found"),
+            DmsError::InvalidNumber => write!(f, "invalid number"),
+            DmsError::UnexpectedCharacter(c) => write!(f, "unexpected character '{}'", c),
+            DmsError::TooManyComponents => write!(f, "too many components"),
+            DmsError::FractionalComponent => {
+                write!(f, "only the last component can be fractional")
+            }
+            DmsError::ComponentOutOfRange => write!(f, "minutes or seconds out of range"),
+            DmsError::ConflictingSign => write!(f, "both sign and hemisphere given"),
+        }
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl std::error::Error for DmsError {}
+
+/// Parses an angle written in degrees, minutes and seconds.
+///
+/// Components are separated by whitespace and/or unit markers (`°`, `º` or
+/// `d` for degrees, `'` or `′` for minutes, `"` or `″` for seconds). Without
+/// markers the components are taken in degrees, minutes, seconds order. Only
+/// the last component can be fractional. The value is negative if it starts
+/// with `-` or has `S` or `W` hemisphere letter as prefix or suffix.
+pub fn parse<T: Scalar>(s: &str) -> Result<Angle<T>, DmsError> {
+    let s = s.trim();
+    let (s, hemisphere) = strip_hemisphere(s);
+
+    let s = s.trim();
+    let (s, sign) = if let Some(s) = s.strip_prefix('-') {
+        (s, Some(true))
+    } else if let Some(s) = s.strip_prefix('+') {
+        (s, Some(false))
+    } else {
+        (s, None)
+    };
+
+    let negative = match (sign, hemisphere) {
+        (Some(_), Some(_)) => return Err(DmsError::ConflictingSign),
+        (Some(negative), None) | (None, Some(negative)) => negative,
+        (None, None) => false,
+    };
+
+    let mut components = Components::default();
+    let mut start = None;
+
+    for (i, c) in s.char_indices() {
+        if c.is_ascii_digit() || c == '.' {
+            start.get_or_insert(i);
+            continue;
+        }
+
+        let marker = match c {
+            '°' | 'º' | 'd' => Some(0),
+            '\'' | '′' => Some(1),
+            '"' | '″' => Some(2),
+            c if c.is_whitespace() => None,
+            c => return Err(DmsError::UnexpectedCharacter(c)),
+        };
+
+        match start.take() {
+            Some(start) => components.push(&s[start..i], marker, c)?,
+            None if marker.is_some() => return Err(DmsError::UnexpectedCharacter(c)),
+            None => {}
+        }
+    }
+
+    if let Some(start) = start {
+        components.push(&s[start..], None, ' ')?;
+    }
+
+    components.to_angle(negative)
+}
+
+fn strip_hemisphere(s: &str) -> (&str, Option<bool>) {
+    let hemisphere = |c: char| match c.to_ascii_uppercase() {
+        'N' | 'E' => Some(false),
+        'S' | 'W' => Some(true),
+        _ => None,
+    };
+
+    let mut chars = s.chars();
+
+    if let Some(negative) = chars.next().and_then(hemisphere) {
+        return (chars.as_str(), Some(negative));
+    }
+
+    let mut chars = s.chars();
+
+    if let Some(negative) = chars.next_back().and_then(hemisphere) {
+        return (chars.as_str(), Some(negative));
+    }
+
+    (s, None)
+}
+
+#[derive(Default)]
+struct Components {
+    values: [f64; 3],
+    next: usize,
+    fractional: bool,
+}
+
+impl Components {
+    fn push(&mut self, token: &str, marker: Option<usize>, c: char) -> Result<(), DmsError> {
+        let index = marker.unwrap_or(self.next);
+
+        if index < self.next {
+            return Err(DmsError::UnexpectedCharacter(c));
+        }
+
+        if index >= self.values.len() {
+            return Err(DmsError::TooManyComponents);
+        }
+
+        if self.fractional {
+            return Err(DmsError::FractionalComponent);
+        }
+
+        let value: f64 = token.parse().map_err(|_| DmsError::InvalidNumber)?;
+
+        if index > 0 && value >= 60.0 {
+            return Err(DmsError::ComponentOutOfRange);
+        }
+
+        self.values[index] = value;
+        self.next = index + 1;
+        self.fractional = token.contains('.');
+
+        Ok(())
+    }
+
+    fn to_angle<T: Scalar>(&self, negative: bool) -> Result<Angle<T>, DmsError> {
+        let [degrees, minutes, seconds] = self.values;
+        let sign = if negative { -1.0 } else { 1.0 };
+
+        // use the finest unit given, so integer parts are combined exactly
+        match self.next {
+            0 => Err(DmsError::Empty),
+            1 => Ok(Angle::degrees(T::from_f64(sign * degrees))),
+            2 => Ok(Angle::arcminutes(T::from_f64(
+                sign * (degrees * 60.0 + minutes),
+            ))),
+            _ => Ok(Angle::arcseconds(T::from_f64(
+                sign * (degrees * 3600.0 + minutes * 60.0 + seconds),
+            ))),
+        }
+    }
+}
+
+/// Display adapter writing an angle in degrees-minutes-seconds or
+/// degrees-decimal-minutes notation, created by [`Angle::dms`] and
+/// [`Angle::dm`].
+#[derive(Copy, Clone, Debug)]
+pub struct DmsFormat {
+    negative: bool,
+    total: u64,
+    precision: u8,
+    seconds: bool,
+    hemispheres: Option<(char, char)>,
+}
+
+impl DmsFormat {
+    /// Largest supported number of decimal places.
+    pub const MAX_PRECISION: u8 = 9;
+
+    pub(crate) fn new(degrees: f64, precision: u8, seconds: bool) -> Self {
+        let precision = precision.min(Self::MAX_PRECISION);
+        let unit = if seconds { 3600.0 } else { 60.0 };
+        let scale = libm::pow(10.0, precision as f64);
+
+        let total = round(libm::fabs(degrees) * unit * scale) as u64;
+
+        Self {
+            negative: degrees < 0.0 && total > 0,
+            total,
+            precision,
+            seconds,
+            hemispheres: None,
+        }
+    }
+
+    /// Writes the sign as hemisphere letter suffix instead, e.g.
+    /// `hemispheres('N', 'S')` for latitude or `hemispheres('E', 'W')` for
+    /// longitude.
+    pub fn hemispheres(self, positive: char, negative: char) -> Self {
+        Self {
+            hemispheres: Some((positive, negative)),
+            ..self
+        }
+    }
+}
+
+impl Display for DmsFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let scale = 10u64.pow(self.precision as u32);
+        let fraction = self.total % scale;
+        let whole = self.total / scale;
+
+        if self.negative && self.hemispheres.is_none() {
+            write!(f, "-")?;
+        }
+
+        if self.seconds {
+            write!(
+                f,
+                "{}°{:02}'{:02}",
+                whole / 3600,
+                whole / 60 % 60,
+                whole % 60
+            )?;
+        } else {
+            write!(f, "{}°{:02}", whole / 60, whole % 60)?;
+        }
+
+        if self.precision > 0 {
+            write!(f, ".{:01$}", fraction, self.precision as usize)?;
+        }
+
+        write!(f, "{}", if self.seconds { '"' } else { '\'' })?;
+
+        match self.hemispheres {
+            Some((_, negative)) if self.negative => write!(f, "{}", negative),
+            Some((positive, _)) => write!(f, "{}", positive),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: Scalar> Angle<T> {
+    /// Degrees-minutes-seconds notation with `precision` decimal places of
+    /// seconds, e.g. `47°36'22.5"`.
+    pub fn dms(&self, precision: u8) -> DmsFormat {
+        DmsFormat::new(self.as_degrees().to_f64(), precision, true)
+    }
+
+    /// Degrees-decimal-minutes notation with `precision` decimal places of
+    /// minutes, e.g. `47°36.375'`.
+    pub fn dm(&self, precision: u8) -> DmsFormat {
+        DmsFormat::new(self.as_degrees().to_f64(), precision, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use std::format;
+
+    #[test]
+    fn parse_notations() {
+        let r = Angle::arcseconds(47.0 * 3600.0 + 36.0 * 60.0 + 22.5);
+
+        assert_eq!(parse("47°36'22.5\"N"), Ok(r));
+        assert_eq!(parse("N 47° 36′ 22.5″"), Ok(r));
+        assert_eq!(parse("47d36'22.5\""), Ok(r));
+        assert_eq!(parse("47 36 22.5"), Ok(r));
+        assert_eq!(
+            parse("47 36.375"),
+            Ok(Angle::arcminutes(47.0 * 60.0 + 36.375))
+        );
+        assert_eq!(
+            parse("47°36.375'n"),
+            Ok(Angle::arcminutes(47.0 * 60.0 + 36.375))
+        );
+        assert_eq!(parse(" 47.6062 "), Ok(Angle::degrees(47.6062)));
+        assert_eq!(parse("47\""), Ok(Angle::arcseconds(47.0)));
+    }
+
+    #[test]
+    fn parse_sign() {
+        let r = Angle::arcseconds(-(122.0 * 3600.0 + 19.0 * 60.0 + 55.1));
+
+        assert_eq!(parse("-122 19 55.1"), Ok(r));
+        assert_eq!(parse("122°19'55.1\"W"), Ok(r));
+        assert_eq!(parse("W122 19 55.1"), Ok(r));
+        assert_eq!(parse::<f32>("33.5S"), Ok(Angle::degrees(-33.5)));
+        assert_eq!(parse::<f64>("+12E"), Err(DmsError::ConflictingSign));
+        assert_eq!(parse::<f64>("-12S"), Err(DmsError::ConflictingSign));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(parse::<f64>(""), Err(DmsError::Empty));
+        assert_eq!(parse::<f64>("N"), Err(DmsError::Empty));
+        assert_eq!(parse::<f64>("47 1.2.3"), Err(DmsError::InvalidNumber));
+        assert_eq!(parse::<f64>("47x"), Err(DmsError::UnexpectedCharacter('x')));
+        assert_eq!(
+            parse::<f64>("47'30°"),
+            Err(DmsError::UnexpectedCharacter('°'))
+        );
+        assert_eq!(parse::<f64>("°47"), Err(DmsError::UnexpectedCharacter('°')));
+        assert_eq!(parse::<f64>("1 2 3 4"), Err(DmsError::TooManyComponents));
+        assert_eq!(parse::<f64>("47.5 30"), Err(DmsError::FractionalComponent));
+        assert_eq!(parse::<f64>("47 60"), Err(DmsError::ComponentOutOfRange));
+        assert_eq!(parse::<f64>("47 30 61"), Err(DmsError::ComponentOutOfRange));
+    }
+
+    #[test]
+    fn format() {
+        let a = Angle::arcseconds(47.0 * 3600.0 + 36.0 * 60.0 + 22.5);
+
+        assert_eq!(format!("{}", a.dms(1)), "47°36'22.5\"");
+        assert_eq!(
+            format!("{}", (a + Angle::arcseconds(0.1)).dms(0)),
+            "47°36'23\""
+        );
+        assert_eq!(format!("{}", a.dm(3)), "47°36.375'");
+        assert_eq!(
+            format!("{}", a.dms(2).hemispheres('N', 'S')),
+            "47°36'22.50\"N"
+        );
+        assert_eq!(format!("{}", (-a).dm(1).hemispheres('E', 'W')), "47°36.4'W");
+        assert_eq!(format!("{}", (-a).dms(1)), "-47°36'22.5\"");
+        assert_eq!(format!("{}", Angle::degrees(-5.0).dm(0)), "-5°00'");
+    }
+
+    #[test]
+    fn format_carry() {
+        let a = Angle::arcseconds(9.0 * 3600.0 + 59.0 * 60.0 + 59.96);
+
+        assert_eq!(format!("{}", a.dms(1)), "10°00'00.0\"");
+        assert_eq!(format!("{}", a.dm(0)), "10°00'");
+        assert_eq!(format!("{}", Angle::arcseconds(-0.01).dms(0)), "0°00'00\"");
+    }
+
+    #[test]
+    fn round_trip() {
+        let a = Angle::arcseconds(-(122.0 * 3600.0 + 19.0 * 60.0 + 55.1));
+        let s = format!("{}", a.dms(1).hemispheres('E', 'W'));
+
+        assert_eq!(parse(&s), Ok(a));
+    }
+}
@@ -0,0 +1,52 @@
+use core::fmt::{Display, Formatter};
+
+/// Reason for rejecting a value passed to one of the fallible constructors.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "use_defmt", derive(defmt::Format))]
+pub enum AngleError {
+    /// Value is NaN.
+    NotANumber,
+    /// Value is positive or negative infinity.
+    Infinite,
+    /// Value lies outside of the normalized range required by a strict
+    /// constructor.
+    OutOfRange,
+}
+
+impl Display for AngleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            AngleError::NotANumber => write!(f, "angle value is NaN"),
+            AngleError::Infinite => write!(f, "angle value is infinite"),
+            AngleError::OutOfRange => write!(f, "angle value is out of normalized range"),
+        }
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl std::error::Error for AngleError {}
+
+/// Reason for rejecting a string parsed into an angle.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "use_defmt", derive(defmt::Format))]
+pub enum ParseAngleError {
+    /// Missing or malformed number.
+    InvalidNumber,
+    /// Suffix not recognized as a unit.
+    UnknownUnit,
+    /// Number parsed, but rejected as angle value.
+    InvalidValue(AngleError),
+}
+
+impl Display for ParseAngleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseAngleError::InvalidNumber => write!(f, "invalid number"),
+            ParseAngleError::UnknownUnit => write!(f, "unknown angle unit"),
+            ParseAngleError::InvalidValue(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl std::error::Error for ParseAngleError {}
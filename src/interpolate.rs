@@ -0,0 +1,141 @@
+//! Interpolation between angles.
+//!
+//! [`Angle::lerp`] follows the shorter arc, so going from `170deg` to
+//! `-170deg` passes through `180deg` instead of spinning the long way around.
+
+use crate::{stats, Angle, Scalar};
+
+/// Smoothstep easing `3t^2 - 2t^3`, with `t` clamped to `[0, 1]`.
+///
+/// Has zero slope at both ends, so e.g. `a.lerp(b, smoothstep(t))` starts and
+/// stops the motion gently.
+pub fn smoothstep<T: Scalar>(t: T) -> T {
+    let t = if t < T::ZERO {
+        T::ZERO
+    } else if t > T::ONE {
+        T::ONE
+    } else {
+        t
+    };
+
+    t * t * (T::from_f64(3.0) - T::from_f64(2.0) * t)
+}
+
+impl<T: Scalar> Angle<T> {
+    /// Interpolates along the shorter arc, `t = 0` gives `self` and `t = 1`
+    /// gives `other`. Values of `t` outside of `[0, 1]` extrapolate. Angles
+    /// exactly half a turn apart are interpolated counter-clockwise.
+    pub fn lerp(self, other: Self, t: T) -> Self {
+        self.lerp_by((other - self).as_radians(), t)
+    }
+
+    /// Interpolates counter-clockwise (increasing angle), even if that is the
+    /// longer arc.
+    pub fn lerp_ccw(self, other: Self, t: T) -> Self {
+        let span = (other - self).as_radians();
+        let span = if span < T::ZERO { span + T::TAU } else { span };
+
+        self.lerp_by(span, t)
+    }
+
+    /// Interpolates clockwise (decreasing angle), even if that is the longer
+    /// arc.
+    pub fn lerp_cw(self, other: Self, t: T) -> Self {
+        let span = (other - self).as_radians();
+        let span = if span > T::ZERO { span - T::TAU } else { span };
+
+        self.lerp_by(span, t)
+    }
+
+    /// Blends any number of weighted angles, i.e. their weighted circular
+    /// mean. For two angles it moves along the shorter arc like [`Angle::lerp`],
+    /// though not at constant speed. `None` if the angles cancel each other.
+    pub fn blend(angles: impl IntoIterator<Item = (Self, T)>) -> Option<Self> {
+        stats::weighted_mean(angles)
+    }
+
+    /// `span` may lie outside of `(-pi, pi]`, so it is only normalized after
+    /// scaling.
+    fn lerp_by(self, span: T, t: T) -> Self {
+        Self::radians(self.value + span * t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shortest() {
+        let a = Angle::degrees(170.0);
+        let b = Angle::degrees(-170.0);
+
+        assert!(a
+            .lerp(b, 0.5)
+            .is_within(&Angle::degrees(180.0), Angle::degrees(0.001)));
+        assert!(a
+            .lerp(b, 0.25)
+            .is_within(&Angle::degrees(175.0), Angle::degrees(0.001)));
+        assert!(b
+            .lerp(a, 0.75)
+            .is_within(&Angle::degrees(175.0), Angle::degrees(0.001)));
+        assert!(a
+            .lerp(b, 2.0)
+            .is_within(&Angle::degrees(-150.0), Angle::degrees(0.001)));
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert!(Angle::<f32>::degrees(0.0)
+            .lerp(Angle::degrees(180.0), 0.5)
+            .is_within(&Angle::degrees(90.0), Angle::degrees(0.001)));
+    }
+
+    #[test]
+    fn directed() {
+        let a = Angle::degrees(170.0);
+        let b = Angle::degrees(-170.0);
+
+        assert!(a
+            .lerp_ccw(b, 0.5)
+            .is_within(&Angle::degrees(180.0), Angle::degrees(0.001)));
+        assert!(a
+            .lerp_cw(b, 0.5)
+            .is_within(&Angle::degrees(0.0), Angle::degrees(0.001)));
+        assert!(b
+            .lerp_ccw(a, 0.5)
+            .is_within(&Angle::degrees(0.0), Angle::degrees(0.001)));
+        assert!(b
+            .lerp_cw(a, 0.5)
+            .is_within(&Angle::degrees(180.0), Angle::degrees(0.001)));
+        assert!(a.lerp_cw(b, 1.0).is_within(&b, Angle::degrees(0.001)));
+        assert_eq!(a.lerp_ccw(a, 0.5), a);
+        assert_eq!(a.lerp_cw(a, 0.5), a);
+    }
+
+    #[test]
+    fn blend() {
+        let blended = Angle::blend([
+            (Angle::degrees(170.0), 1.0),
+            (Angle::degrees(-170.0), 1.0),
+            (Angle::degrees(90.0), 0.0),
+        ]);
+
+        assert!(blended
+            .unwrap()
+            .is_within(&Angle::degrees(180.0), Angle::degrees(0.001)));
+        assert!(Angle::<f64>::blend([]).is_none());
+    }
+
+    #[test]
+    fn smooth() {
+        assert_eq!(smoothstep(-1.0), 0.0);
+        assert_eq!(smoothstep(0.5), 0.5);
+        assert_eq!(smoothstep(2.0f32), 1.0);
+        assert!(smoothstep(0.25) < 0.25);
+
+        let a = Angle::degrees(170.0);
+        let b = Angle::degrees(-170.0);
+
+        assert!(a
+            .lerp(b, smoothstep(0.5))
+            .is_within(&Angle::degrees(180.0), Angle::degrees(0.001)));
+    }
+}
@@ -1,65 +1,312 @@
 #![cfg_attr(not(feature = "use_std"), no_std)]
 
+mod arc;
+mod arc_set;
+mod bearing;
+mod binary;
+pub mod compass;
+mod direction;
+mod display;
+pub mod dms;
+mod error;
+pub mod interpolate;
+pub mod magnetometer;
+pub mod north;
+mod parse;
+mod scalar;
+#[cfg(feature = "use_serde")]
+pub mod serde;
+pub mod stats;
+mod unit;
+mod unsigned;
+mod unwrapped;
+
 use core::{
-    f64::consts::{FRAC_PI_2, PI},
-    ops::{Add, Sub},
+    cmp::Ordering,
+    f64::consts::FRAC_PI_2,
+    hash::{Hash, Hasher},
+    iter::Sum,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign},
 };
-use libm::{cos, fabs, sin};
-#[cfg(feature = "use_std")]
-use std::fmt::{Display, Formatter};
-
-pub type Radians = f64;
-pub type Degrees = f64;
 
+pub use arc::{Arc, ArcSteps};
+pub use arc_set::ArcSet;
+pub use bearing::Bearing;
+pub use binary::BinaryAngle;
+pub use direction::Direction;
+pub use display::AngleDisplay;
+pub use error::{AngleError, ParseAngleError};
+pub use scalar::Scalar;
+pub use unit::Unit;
+pub use unsigned::UnsignedAngle;
+pub use unwrapped::UnwrappedAngle;
+
+pub type Radians<T = f64> = T;
+pub type Degrees<T = f64> = T;
+pub type Turns<T = f64> = T;
+pub type Gradians<T = f64> = T;
+pub type ArcMinutes<T = f64> = T;
+pub type ArcSeconds<T = f64> = T;
+/// NATO mils, 6400 in a full turn.
+pub type Mils<T = f64> = T;
+
+#[deprecated(note = "use `Angle::QUARTER_TURN` instead")]
 pub const RADIANS_90_DEGREES: Radians = FRAC_PI_2;
 
-#[derive(Copy, Clone)]
-pub struct Angle {
-    value: f64,
+/// Angle normalized into the signed range `(-pi, pi]`.
+///
+/// Never holds NaN: the infallible constructors and operators turn NaN and
+/// infinite values into a zero angle. Use [`Angle::try_radians`] or
+/// [`Angle::try_degrees`] to detect such input instead.
+///
+/// The range is half-open: `-pi` is always stored as `pi`. Equality and hashing
+/// additionally fold `-0.0` into `0.0`, so two angles describing the same
+/// direction are always equal.
+#[derive(Copy, Clone, Debug)]
+pub struct Angle<T = f64> {
+    value: T,
 }
 
-impl Angle {
-    pub fn radians(value: Radians) -> Self {
+impl<T: Scalar> Angle<T> {
+    pub const ZERO: Self = Self { value: T::ZERO };
+    pub const QUARTER_TURN: Self = Self {
+        value: T::FRAC_PI_2,
+    };
+    pub const HALF_TURN: Self = Self { value: T::PI };
+
+    pub fn radians(value: Radians<T>) -> Self {
         Self { value }.normalize()
     }
 
-    pub fn degrees(value: Degrees) -> Self {
-        Self::radians(value.to_radians())
+    /// Reduces the value in degrees before converting it, so whole turns are
+    /// removed exactly and e.g. `450.0` gives the same angle as `90.0`. The
+    /// same applies to all other units below.
+    pub fn degrees(value: Degrees<T>) -> Self {
+        Self::from_full_turn(value, 360.0)
+    }
+
+    pub fn turns(value: Turns<T>) -> Self {
+        Self::from_full_turn(value, 1.0)
+    }
+
+    pub fn gradians(value: Gradians<T>) -> Self {
+        Self::from_full_turn(value, 400.0)
+    }
+
+    pub fn arcminutes(value: ArcMinutes<T>) -> Self {
+        Self::from_full_turn(value, 360.0 * 60.0)
+    }
+
+    pub fn arcseconds(value: ArcSeconds<T>) -> Self {
+        Self::from_full_turn(value, 360.0 * 3600.0)
+    }
+
+    pub fn mils(value: Mils<T>) -> Self {
+        Self::from_full_turn(value, 6400.0)
+    }
+
+    pub fn try_radians(value: Radians<T>) -> Result<Self, AngleError> {
+        Self::check(value).map(Self::radians)
+    }
+
+    pub fn try_degrees(value: Degrees<T>) -> Result<Self, AngleError> {
+        Self::check(value).map(Self::degrees)
+    }
+
+    /// Accepts only values already normalized into `(-pi, pi]`.
+    pub fn try_radians_strict(value: Radians<T>) -> Result<Self, AngleError> {
+        match Self::check(value) {
+            Ok(value) if value > -T::PI && value <= T::PI => Ok(Self { value }),
+            Ok(_) => Err(AngleError::OutOfRange),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Accepts only values already normalized into `(-180, 180]`.
+    pub fn try_degrees_strict(value: Degrees<T>) -> Result<Self, AngleError> {
+        let half_turn = T::from_f64(180.0);
+
+        match Self::check(value) {
+            Ok(value) if value > -half_turn && value <= half_turn => Ok(Self::degrees(value)),
+            Ok(_) => Err(AngleError::OutOfRange),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Angle of the point `(x, y)` measured from the positive X axis.
+    pub fn atan2(y: T, x: T) -> Self {
+        Self::radians(y.atan2(x))
+    }
+
+    /// Returns `None` if `value` is outside of `[-1, 1]`.
+    pub fn asin(value: T) -> Option<Self> {
+        if value >= -T::ONE && value <= T::ONE {
+            Some(Self::radians(value.asin()))
+        } else {
+            None
+        }
     }
 
-    pub fn as_radians(&self) -> Radians {
+    /// Returns `None` if `value` is outside of `[-1, 1]`.
+    pub fn acos(value: T) -> Option<Self> {
+        if value >= -T::ONE && value <= T::ONE {
+            Some(Self::radians(value.acos()))
+        } else {
+            None
+        }
+    }
+
+    /// Angle with given sine and cosine, which do not need to be normalized.
+    pub fn from_sin_cos(sin: T, cos: T) -> Self {
+        Self::atan2(sin, cos)
+    }
+
+    /// Direction of the vector `(x, y)`.
+    pub fn from_vector(x: T, y: T) -> Self {
+        Self::atan2(y, x)
+    }
+
+    pub fn as_radians(&self) -> Radians<T> {
         self.value
     }
 
-    pub fn as_degrees(&self) -> Degrees {
-        self.value.to_degrees()
+    /// Converts back the same way as the constructors, so e.g.
+    /// `Angle::degrees(a.as_degrees()) == a` for any angle created from
+    /// degrees. The same applies to all other units below.
+    pub fn as_degrees(&self) -> Degrees<T> {
+        self.as_full_turn(360.0)
+    }
+
+    pub fn as_turns(&self) -> Turns<T> {
+        self.as_full_turn(1.0)
+    }
+
+    pub fn as_gradians(&self) -> Gradians<T> {
+        self.as_full_turn(400.0)
+    }
+
+    pub fn as_arcminutes(&self) -> ArcMinutes<T> {
+        self.as_full_turn(360.0 * 60.0)
+    }
+
+    pub fn as_arcseconds(&self) -> ArcSeconds<T> {
+        self.as_full_turn(360.0 * 3600.0)
+    }
+
+    pub fn as_mils(&self) -> Mils<T> {
+        self.as_full_turn(6400.0)
     }
 
     pub fn abs(&self) -> Self {
         Self {
-            value: fabs(self.value),
+            value: self.value.abs(),
         }
     }
 
-    pub fn cos(&self) -> f64 {
-        cos(self.as_radians())
+    pub fn cos(&self) -> T {
+        self.as_radians().cos()
+    }
+
+    pub fn sin(&self) -> T {
+        self.as_radians().sin()
+    }
+
+    pub fn tan(&self) -> T {
+        self.as_radians().tan()
     }
 
-    pub fn sin(&self) -> f64 {
-        sin(self.as_radians())
+    pub fn sin_cos(&self) -> (T, T) {
+        (self.sin(), self.cos())
     }
 
-    pub fn is_within(&self, other: &Angle, difference: Angle) -> bool {
-        (self.clone() - other.clone()).abs().as_radians() < difference.as_radians()
+    /// True if angles are less than `difference` apart, see
+    /// [`Angle::is_within_inclusive`] for the inclusive variant.
+    pub fn is_within(&self, other: &Angle<T>, difference: Angle<T>) -> bool {
+        self.shortest_to(other).abs().as_radians() < difference.as_radians()
+    }
+
+    /// Total order on the canonical value.
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        self.canonical().total_cmp(&other.canonical())
+    }
+
+    fn canonical(&self) -> T {
+        self.value + T::ZERO
+    }
+
+    fn from_full_turn(value: T, full_turn: f64) -> Self {
+        let half_turn = T::from_f64(full_turn / 2.0);
+        let full_turn = T::from_f64(full_turn);
+
+        // fmod is exact, so are the folds below
+        let value = value % full_turn;
+
+        let value = if value > half_turn {
+            value - full_turn
+        } else if value <= -half_turn {
+            value + full_turn
+        } else {
+            value
+        };
+
+        // dividing first keeps e.g. 90deg and 100grad bit-identical
+        Self::radians(value / full_turn * T::TAU)
+    }
+
+    fn as_full_turn(&self, full_turn: f64) -> T {
+        let value = self.value / T::TAU * T::from_f64(full_turn);
+
+        // from_full_turn may round the scaled value differently, a single
+        // correction step finds the value converting back exactly whenever
+        // there is one
+        let error = (Self::from_full_turn(value, full_turn) - *self).as_radians();
+
+        if error == T::ZERO {
+            return value;
+        }
+
+        let corrected = value - error / T::TAU * T::from_f64(full_turn);
+
+        if Self::from_full_turn(corrected, full_turn) == *self {
+            corrected
+        } else {
+            value
+        }
+    }
+
+    fn check(value: T) -> Result<T, AngleError> {
+        if value.is_nan() {
+            Err(AngleError::NotANumber)
+        } else if value.is_infinite() {
+            Err(AngleError::Infinite)
+        } else {
+            Ok(value)
+        }
     }
 
     fn normalize(self) -> Self {
-        let value = self.value % (2.0 * PI);
+        let value = self.value;
+
+        let value = if value > -T::PI && value <= T::PI {
+            value
+        } else if value.abs() <= T::PI + T::TAU {
+            // a single fold, as done after adding two normalized angles
+            if value > T::ZERO {
+                value - T::TAU
+            } else {
+                value + T::TAU
+            }
+        } else if value.is_nan() || value.is_infinite() {
+            T::ZERO
+        } else {
+            // sin and cos reduce their argument with full precision, unlike
+            // taking remainder of division by inexact 2pi
+            value.sin().atan2(value.cos())
+        };
 
-        let value = if value > PI {
-            value - 2.0 * PI
-        } else if value < -PI {
-            value + 2.0 * PI
+        // rounding may still land exactly on -pi
+        let value = if value <= -T::PI {
+            value + T::TAU
         } else {
             value
         };
@@ -68,7 +315,30 @@ impl Angle {
     }
 }
 
-impl Add for Angle {
+impl<T: Scalar> PartialEq for Angle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.canonical().to_bits() == other.canonical().to_bits()
+    }
+}
+
+impl<T: Scalar> Eq for Angle<T> {}
+
+/// Orders angles by their signed value in `(-pi, pi]`, i.e. cutting the circle
+/// at the `+-180deg` seam. This is not a measure of which angle is "ahead" of
+/// the other.
+impl<T: Scalar> PartialOrd for Angle<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.total_cmp(other))
+    }
+}
+
+impl<T: Scalar> Hash for Angle<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.canonical().to_bits().hash(state);
+    }
+}
+
+impl<T: Scalar> Add for Angle<T> {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
@@ -77,7 +347,7 @@ impl Add for Angle {
     }
 }
 
-impl Sub for Angle {
+impl<T: Scalar> Sub for Angle<T> {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
@@ -86,35 +356,129 @@ impl Sub for Angle {
     }
 }
 
-#[cfg(feature = "use_std")]
-impl Display for Angle {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}deg", self.as_degrees())
+impl<T: Scalar> Neg for Angle<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        let value = -self.value;
+        Self { value }.normalize()
     }
 }
 
+/// Scales the normalized value and normalizes the result again.
+///
+/// Full turns are not tracked, so e.g. `Angle::degrees(170.0) * 2.0` is
+/// `-20deg` and `Angle::degrees(-170.0) * 2.0` is `20deg`, not `340deg` and
+/// `-340deg`. Use [`UnwrappedAngle`] when turns matter.
+impl<T: Scalar> Mul<T> for Angle<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        let value = self.value * rhs;
+        Self { value }.normalize()
+    }
+}
+
+/// Divides the normalized value, so the result always lies within
+/// `(-pi / |rhs|, pi / |rhs|]` for `|rhs| >= 1`, e.g. halving `-170deg` gives
+/// `-85deg`.
+impl<T: Scalar> Div<T> for Angle<T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        let value = self.value / rhs;
+        Self { value }.normalize()
+    }
+}
+
+/// Remainder of the normalized value divided by `rhs`, with the sign of
+/// `self`.
+impl<T: Scalar> Rem for Angle<T> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        let value = self.value % rhs.value;
+        Self { value }.normalize()
+    }
+}
+
+macro_rules! impl_scalar_mul_angle {
+    ($t:ty) => {
+        impl Mul<Angle<$t>> for $t {
+            type Output = Angle<$t>;
+
+            fn mul(self, rhs: Angle<$t>) -> Self::Output {
+                rhs * self
+            }
+        }
+    };
+}
+
+impl_scalar_mul_angle!(f32);
+impl_scalar_mul_angle!(f64);
+
+impl<T: Scalar> AddAssign for Angle<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Scalar> SubAssign for Angle<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Scalar> MulAssign<T> for Angle<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Scalar> DivAssign<T> for Angle<T> {
+    fn div_assign(&mut self, rhs: T) {
+        *self = *self / rhs;
+    }
+}
+
+impl<T: Scalar> Sum for Angle<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Angle::radians(T::ZERO), Add::add)
+    }
+}
+
+impl<'a, T: Scalar> Sum<&'a Angle<T>> for Angle<T> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+/// Sends degrees as `f32` regardless of the backend, see [`AngleDisplay`] for
+/// other units and [`BinaryAngle`] for even more compact encoding.
 #[cfg(feature = "use_defmt")]
-impl defmt::Format for Angle {
+impl<T: Scalar> defmt::Format for Angle<T> {
     fn format(&self, f: defmt::Formatter) {
-        defmt::write!(f, "{}deg", self.as_degrees());
+        defmt::write!(f, "{=f32}deg", self.as_degrees().to_f32());
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use core::f64::consts::{FRAC_PI_2, PI, TAU};
+    use libm::{fabs, fabsf};
 
     #[test]
     fn within() {
-        let a1 = Angle::radians(RADIANS_90_DEGREES);
-        let a2 = Angle::radians(RADIANS_90_DEGREES);
+        let a1 = Angle::QUARTER_TURN;
+        let a2 = Angle::radians(FRAC_PI_2);
 
         assert!(a1.is_within(&a2, Angle::degrees(0.001)));
     }
 
     #[test]
     fn deg_to_rad() {
-        let a1 = Angle::radians(RADIANS_90_DEGREES);
+        let a1 = Angle::radians(FRAC_PI_2);
         let a2 = Angle::degrees(90.0);
 
         assert!(a1.is_within(&a2, Angle::degrees(0.001)));
@@ -176,7 +540,7 @@ mod tests {
             (0.0, 0.0),
             (15.0, 0.2588),
             (30.0, 0.5),
-            (45.0, 0.7071),
+            (45.0, core::f64::consts::FRAC_1_SQRT_2),
             (60.0, 0.8660),
             (80.0, 0.9848),
             (90.0, 1.0),
@@ -190,4 +554,333 @@ mod tests {
             assert!(fabs(beta.cos() - sin_alpha) < 0.001);
         }
     }
+
+    #[test]
+    fn single_precision() {
+        let a1 = Angle::<f32>::degrees(90.0);
+        let a2 = Angle::<f32>::degrees(180.0);
+        let r = Angle::<f32>::degrees(-90.0);
+
+        assert!((a1 + a2).is_within(&r, Angle::degrees(0.001)));
+        assert!((a1 - a2).is_within(&r, Angle::degrees(0.001)));
+        assert!(fabsf(a1.sin() - 1.0) < 0.001);
+        assert!(fabsf(a1.cos()) < 0.001);
+    }
+
+    #[test]
+    fn single_precision_norm() {
+        let a1 = Angle::<f32>::degrees(90.0);
+        let a2 = Angle::<f32>::degrees(90.0 + 360.0 * 7.0);
+        let a3 = Angle::<f32>::radians(core::f32::consts::FRAC_PI_2);
+
+        assert!(a1.is_within(&a2, Angle::degrees(0.01)));
+        assert!(a1.is_within(&a3, Angle::degrees(0.001)));
+    }
+
+    #[test]
+    fn neg() {
+        let a1 = Angle::degrees(90.0);
+        let r = Angle::degrees(-90.0);
+
+        assert!((-a1).is_within(&r, Angle::degrees(0.001)));
+        assert!((-r).is_within(&a1, Angle::degrees(0.001)));
+    }
+
+    #[test]
+    fn mul_div() {
+        let a1 = Angle::degrees(170.0);
+        let a2 = Angle::degrees(-170.0);
+
+        assert!((a1 * 2.0).is_within(&Angle::degrees(-20.0), Angle::degrees(0.001)));
+        assert!((2.0_f64 * a2).is_within(&Angle::degrees(20.0), Angle::degrees(0.001)));
+        assert!((a1 / 2.0).is_within(&Angle::degrees(85.0), Angle::degrees(0.001)));
+        assert!((a2 / 2.0).is_within(&Angle::degrees(-85.0), Angle::degrees(0.001)));
+        assert!((0.5_f32 * Angle::<f32>::degrees(90.0))
+            .is_within(&Angle::degrees(45.0), Angle::degrees(0.001)));
+    }
+
+    #[test]
+    fn rem() {
+        let a1 = Angle::degrees(100.0);
+        let a2 = Angle::degrees(-100.0);
+        let step = Angle::degrees(30.0);
+
+        assert!((a1 % step).is_within(&Angle::degrees(10.0), Angle::degrees(0.001)));
+        assert!((a2 % step).is_within(&Angle::degrees(-10.0), Angle::degrees(0.001)));
+    }
+
+    #[test]
+    fn assign() {
+        let mut a = Angle::degrees(90.0);
+
+        a += Angle::degrees(100.0);
+        assert!(a.is_within(&Angle::degrees(-170.0), Angle::degrees(0.001)));
+
+        a -= Angle::degrees(20.0);
+        assert!(a.is_within(&Angle::degrees(170.0), Angle::degrees(0.001)));
+
+        a *= 3.0;
+        assert!(a.is_within(&Angle::degrees(150.0), Angle::degrees(0.001)));
+
+        a /= 5.0;
+        assert!(a.is_within(&Angle::degrees(30.0), Angle::degrees(0.001)));
+    }
+
+    #[test]
+    fn sum() {
+        let angles = [
+            Angle::degrees(90.0),
+            Angle::degrees(120.0),
+            Angle::degrees(150.0),
+        ];
+
+        let r = Angle::degrees(0.0);
+
+        assert!(angles
+            .iter()
+            .sum::<Angle>()
+            .is_within(&r, Angle::degrees(0.001)));
+        assert!(angles
+            .into_iter()
+            .sum::<Angle>()
+            .is_within(&r, Angle::degrees(0.001)));
+    }
+
+    #[test]
+    fn eq() {
+        assert_eq!(Angle::degrees(90.0), Angle::degrees(90.0 + 360.0));
+        assert_eq!(Angle::radians(-PI), Angle::radians(PI));
+        assert_eq!(Angle::radians(-0.0), Angle::radians(0.0));
+        assert_ne!(Angle::degrees(90.0), Angle::degrees(-90.0));
+    }
+
+    #[test]
+    fn ord() {
+        let mut angles = [
+            Angle::degrees(90.0),
+            Angle::radians(-PI),
+            Angle::degrees(-45.0),
+            Angle::degrees(0.0),
+        ];
+
+        assert!(Angle::degrees(-90.0) < Angle::degrees(90.0));
+        assert!(Angle::radians(-PI) > Angle::degrees(90.0));
+
+        angles.sort_by(Angle::total_cmp);
+
+        assert_eq!(
+            angles,
+            [
+                Angle::degrees(-45.0),
+                Angle::degrees(0.0),
+                Angle::degrees(90.0),
+                Angle::radians(PI),
+            ]
+        );
+    }
+
+    #[test]
+    fn hash() {
+        extern crate std;
+        use std::collections::HashSet;
+
+        let mut set = HashSet::new();
+
+        set.insert(Angle::radians(PI));
+        set.insert(Angle::radians(-PI));
+        set.insert(Angle::radians(0.0));
+        set.insert(Angle::radians(-0.0));
+        set.insert(Angle::degrees(90.0));
+
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&Angle::degrees(90.0 - 360.0 * 3.0)));
+        assert!(set.contains(&Angle::radians(-PI)));
+    }
+
+    #[test]
+    fn inverse() {
+        let r = Angle::degrees(30.0);
+
+        assert!(Angle::asin(0.5)
+            .unwrap()
+            .is_within(&r, Angle::degrees(0.001)));
+        assert!(Angle::acos(0.5)
+            .unwrap()
+            .is_within(&Angle::degrees(60.0), Angle::degrees(0.001)));
+        assert!(Angle::asin(1.5).is_none());
+        assert!(Angle::acos(-1.0001).is_none());
+        assert!(Angle::acos(f64::NAN).is_none());
+        assert!(Angle::<f32>::asin(-1.0)
+            .unwrap()
+            .is_within(&Angle::degrees(-90.0), Angle::degrees(0.001)));
+    }
+
+    #[test]
+    fn atan2() {
+        assert!(Angle::atan2(1.0, -1.0).is_within(&Angle::degrees(135.0), Angle::degrees(0.001)));
+        assert!(Angle::from_vector(-1.0, -1.0)
+            .is_within(&Angle::degrees(-135.0), Angle::degrees(0.001)));
+        assert!(
+            Angle::from_vector(-1.0, 0.0).is_within(&Angle::degrees(180.0), Angle::degrees(0.001))
+        );
+
+        let r = Angle::degrees(-70.0);
+        let (sin, cos) = r.sin_cos();
+
+        assert!(Angle::from_sin_cos(sin * 3.0, cos * 3.0).is_within(&r, Angle::degrees(0.001)));
+        assert!(fabs(Angle::degrees(45.0).tan() - 1.0) < 0.001);
+    }
+
+    #[test]
+    fn never_nan() {
+        let zero = Angle::degrees(0.0);
+
+        assert_eq!(Angle::radians(f64::NAN), zero);
+        assert_eq!(Angle::degrees(f64::INFINITY), zero);
+        assert_eq!(
+            Angle::<f32>::radians(f32::NEG_INFINITY),
+            Angle::degrees(0.0)
+        );
+        assert_eq!(Angle::degrees(90.0) * f64::NAN, zero);
+        assert_eq!(Angle::atan2(f64::NAN, 1.0), zero);
+    }
+
+    #[test]
+    fn try_new() {
+        assert_eq!(Angle::try_degrees(450.0), Ok(Angle::degrees(90.0)));
+        assert_eq!(Angle::try_radians(f64::NAN), Err(AngleError::NotANumber));
+        assert_eq!(
+            Angle::<f32>::try_degrees(f32::INFINITY),
+            Err(AngleError::Infinite)
+        );
+        assert_eq!(
+            Angle::try_radians(f64::NEG_INFINITY),
+            Err(AngleError::Infinite)
+        );
+    }
+
+    #[test]
+    fn try_new_strict() {
+        assert_eq!(Angle::try_radians_strict(PI), Ok(Angle::radians(PI)));
+        assert_eq!(Angle::try_degrees_strict(180.0), Ok(Angle::degrees(180.0)));
+        assert_eq!(Angle::try_degrees_strict(-90.0), Ok(Angle::degrees(-90.0)));
+        assert_eq!(Angle::try_radians_strict(-PI), Err(AngleError::OutOfRange));
+        assert_eq!(
+            Angle::try_degrees_strict(-180.0),
+            Err(AngleError::OutOfRange)
+        );
+        assert_eq!(
+            Angle::try_degrees_strict(270.0),
+            Err(AngleError::OutOfRange)
+        );
+        assert_eq!(
+            Angle::try_degrees_strict(f64::NAN),
+            Err(AngleError::NotANumber)
+        );
+    }
+
+    #[test]
+    fn norm_degrees_exact() {
+        let a1 = Angle::degrees(90.0);
+
+        for turns in -1000..1000 {
+            let turns = turns as f64 * 360.0;
+
+            assert_eq!(Angle::degrees(90.0 + turns), a1);
+            assert_eq!(Angle::degrees(180.0 + turns).as_radians(), PI);
+            assert_eq!(Angle::degrees(-180.0 + turns).as_radians(), PI);
+            assert_eq!(Angle::degrees(turns).as_radians(), 0.0);
+        }
+
+        assert_eq!(
+            Angle::<f32>::degrees(-180.0 + 360.0 * 100.0),
+            Angle::degrees(180.0)
+        );
+        assert_eq!(Angle::<f32>::degrees(450.0), Angle::degrees(90.0));
+    }
+
+    #[test]
+    fn norm_boundaries() {
+        let values = [
+            PI,
+            -PI,
+            f64::from_bits(PI.to_bits() + 1),
+            f64::from_bits(PI.to_bits() - 1),
+            -f64::from_bits(PI.to_bits() + 1),
+            -f64::from_bits(PI.to_bits() - 1),
+            3.0 * PI,
+            -3.0 * PI,
+            TAU,
+            -TAU,
+            1e6,
+            -1e15,
+            1e300,
+            f64::MAX,
+            f64::MIN,
+            f64::MIN_POSITIVE,
+            -0.0,
+        ];
+
+        for value in values {
+            let a = Angle::radians(value).as_radians();
+
+            assert!(a > -PI && a <= PI);
+        }
+
+        for step in -10000..10000 {
+            let a = Angle::radians(step as f64 * 0.01 * PI).as_radians();
+
+            assert!(a > -PI && a <= PI);
+        }
+
+        assert_eq!(Angle::radians(-PI).as_radians(), PI);
+        assert_eq!(
+            Angle::<f32>::radians(-core::f32::consts::PI),
+            Angle::radians(core::f32::consts::PI)
+        );
+        assert_eq!((Angle::radians(PI) + Angle::radians(PI)).as_radians(), 0.0);
+        assert_eq!(-Angle::radians(PI), Angle::radians(PI));
+    }
+
+    #[test]
+    fn norm_large() {
+        // sin(1e22) and cos(1e22) correctly rounded
+        let a = Angle::radians(1e22);
+
+        assert!(fabs(a.sin() - -0.8522008497671888) < 1e-15);
+        assert!(fabs(a.cos() - 0.5232147853951389) < 1e-15);
+    }
+
+    #[test]
+    fn units() {
+        let a = Angle::degrees(-90.0);
+
+        assert_eq!(Angle::turns(-0.25), a);
+        assert_eq!(Angle::turns(2.75), a);
+        assert_eq!(Angle::gradians(300.0), a);
+        assert_eq!(Angle::arcminutes(-90.0 * 60.0), a);
+        assert_eq!(Angle::arcseconds(270.0 * 3600.0), a);
+        assert_eq!(Angle::mils(-1600.0), a);
+        assert_eq!(Angle::mils(4800.0 + 6400.0 * 10.0), a);
+
+        assert!(fabs(a.as_turns() + 0.25) < 1e-12);
+        assert!(fabs(a.as_gradians() + 100.0) < 1e-12);
+        assert!(fabs(a.as_arcminutes() + 5400.0) < 1e-9);
+        assert!(fabs(a.as_arcseconds() + 324000.0) < 1e-9);
+        assert!(fabs(a.as_mils() + 1600.0) < 1e-9);
+        assert!(fabsf(Angle::<f32>::mils(800.0).as_degrees() - 45.0) < 1e-5);
+    }
+
+    #[test]
+    fn constants() {
+        assert_eq!(Angle::ZERO, Angle::degrees(0.0));
+        assert_eq!(Angle::QUARTER_TURN, Angle::degrees(90.0));
+        assert_eq!(Angle::HALF_TURN, Angle::turns(0.5));
+        assert_eq!(Angle::HALF_TURN, Angle::turns(-0.5));
+        assert_eq!(Angle::<f32>::HALF_TURN, Angle::degrees(-180.0));
+        assert_eq!(
+            Angle::<f64>::QUARTER_TURN + Angle::QUARTER_TURN,
+            Angle::HALF_TURN
+        );
+    }
 }
@@ -0,0 +1,463 @@
+//! Tilt-compensated heading from a 3-axis magnetometer and accelerometer.
+//!
+//! Sensor axes follow the aerospace convention: X forward, Y right and Z
+//! down, with the accelerometer reading `+1g` along Z when level (i.e. the
+//! direction of gravity, as in Freescale AN4248). Units of both sensors do
+//! not matter, only directions are used.
+//!
+//! [`Calibration::fit`] estimates hard-iron offset and soft-iron matrix from
+//! raw samples taken while rotating the sensor in all directions.
+
+use crate::{Angle, Bearing, Scalar};
+use libm::{fabs, pow, sqrt};
+
+/// Hard-iron offset and soft-iron matrix, applied as `matrix * (raw - offset)`.
+#[derive(Copy, Clone, Debug)]
+pub struct Calibration<T = f64> {
+    offset: [T; 3],
+    matrix: [[T; 3]; 3],
+}
+
+impl<T: Scalar> Default for Calibration<T> {
+    fn default() -> Self {
+        Self::new([T::ZERO; 3], IDENTITY.map(|row| row.map(T::from_f64)))
+    }
+}
+
+const IDENTITY: [[f64; 3]; 3] = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+impl<T: Scalar> Calibration<T> {
+    /// `matrix` is given row by row.
+    pub fn new(offset: [T; 3], matrix: [[T; 3]; 3]) -> Self {
+        Self { offset, matrix }
+    }
+
+    pub fn offset(&self) -> [T; 3] {
+        self.offset
+    }
+
+    pub fn matrix(&self) -> [[T; 3]; 3] {
+        self.matrix
+    }
+
+    pub fn apply(&self, raw: [T; 3]) -> [T; 3] {
+        let v = [0, 1, 2].map(|i| raw[i] - self.offset[i]);
+
+        self.matrix
+            .map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2])
+    }
+
+    /// Fits an ellipsoid to raw samples, giving its center as the offset and
+    /// a symmetric matrix mapping it onto a sphere of the same volume, so the
+    /// corrected field keeps its usual magnitude.
+    ///
+    /// Needs at least 9 samples spread over all orientations, `None` if they
+    /// do not determine an ellipsoid (e.g. all taken in a single plane).
+    pub fn fit(samples: &[[T; 3]]) -> Option<Self> {
+        if samples.len() < 9 {
+            return None;
+        }
+
+        let samples = samples
+            .iter()
+            .map(|sample| sample.map(|value| value.to_f64()));
+        let count = samples.len() as f64;
+
+        // the fit below only works with the origin inside the ellipsoid, so
+        // samples are moved to their mean, which always is, and scaled to unit
+        // spread to keep the normal equations well conditioned
+        let mean = samples.clone().fold([0.0; 3], |sum, sample| {
+            [0, 1, 2].map(|i| sum[i] + sample[i] / count)
+        });
+        let spread = sqrt(
+            samples
+                .clone()
+                .map(|sample| {
+                    (0..3)
+                        .map(|i| (sample[i] - mean[i]) * (sample[i] - mean[i]))
+                        .sum::<f64>()
+                })
+                .sum::<f64>()
+                / count,
+        );
+
+        if spread.is_nan() || spread <= 0.0 {
+            return None;
+        }
+
+        // least squares of x^T M x + 2 v^T x = 1 over the samples, with
+        // unknowns [Mxx, Myy, Mzz, Mxy, Mxz, Myz, vx, vy, vz]
+        let mut normal = [[0.0; 10]; 9];
+
+        for sample in samples {
+            let [x, y, z] = [0, 1, 2].map(|i| (sample[i] - mean[i]) / spread);
+            let row = [
+                x * x,
+                y * y,
+                z * z,
+                2.0 * x * y,
+                2.0 * x * z,
+                2.0 * y * z,
+                2.0 * x,
+                2.0 * y,
+                2.0 * z,
+            ];
+
+            for i in 0..9 {
+                for j in 0..9 {
+                    normal[i][j] += row[i] * row[j];
+                }
+
+                normal[i][9] += row[i];
+            }
+        }
+
+        let [a, b, c, d, e, f, g, h, i] = solve(normal)?;
+        let m = [[a, d, e], [d, b, f], [e, f, c]];
+
+        // center solves M o = -v
+        let inverse = invert(m)?;
+        let center = [0, 1, 2].map(|r| -(0..3).map(|k| inverse[r][k] * [g, h, i][k]).sum::<f64>());
+
+        // (x - o)^T M (x - o) = 1 + o^T M o
+        let scale = 1.0
+            + (0..3)
+                .map(|r| (0..3).map(|k| center[r] * m[r][k] * center[k]).sum::<f64>())
+                .sum::<f64>();
+
+        let (values, vectors) = eigen(m);
+
+        if scale <= 0.0 || values.iter().any(|value| *value <= 0.0) {
+            return None;
+        }
+
+        // symmetric square root of M / scale maps the ellipsoid onto the unit
+        // sphere, multiplying by geometric mean of the radii keeps its volume
+        let radius = pow(
+            scale * scale * scale / (values[0] * values[1] * values[2]),
+            1.0 / 6.0,
+        );
+        let roots = values.map(|value| sqrt(value / scale) * radius);
+
+        let matrix = [0, 1, 2].map(|r| {
+            [0, 1, 2].map(|c| {
+                let value = (0..3)
+                    .map(|k| vectors[r][k] * roots[k] * vectors[c][k])
+                    .sum();
+
+                T::from_f64(value)
+            })
+        });
+
+        // matrix has unit determinant, so undoing the scaling only moves the
+        // center
+        let center = [0, 1, 2].map(|i| T::from_f64(mean[i] + center[i] * spread));
+
+        Some(Self::new(center, matrix))
+    }
+}
+
+/// Orientation computed by [`tilt_compensated`].
+#[derive(Copy, Clone, Debug)]
+pub struct Attitude<T = f64> {
+    roll: Angle<T>,
+    pitch: Angle<T>,
+    heading: Angle<T>,
+}
+
+impl<T: Scalar> Attitude<T> {
+    /// Rotation around X, positive with right side down.
+    pub fn roll(&self) -> Angle<T> {
+        self.roll
+    }
+
+    /// Rotation around Y, positive with nose up, within `[-pi/2, pi/2]`.
+    pub fn pitch(&self) -> Angle<T> {
+        self.pitch
+    }
+
+    /// Direction of the X axis relative to magnetic north, in the usual
+    /// [`Angle`] convention (counter-clockwise from east).
+    pub fn heading(&self) -> Angle<T> {
+        self.heading
+    }
+
+    /// Same as [`Attitude::heading`] as compass bearing.
+    pub fn bearing(&self) -> Bearing<T> {
+        Bearing::from_angle(self.heading)
+    }
+}
+
+/// Computes roll, pitch and magnetic heading from raw magnetometer and
+/// accelerometer readings, correcting the magnetometer with `calibration`.
+pub fn tilt_compensated<T: Scalar>(
+    mag: [T; 3],
+    accel: [T; 3],
+    calibration: &Calibration<T>,
+) -> Attitude<T> {
+    let [bx, by, bz] = calibration.apply(mag);
+    let [gx, gy, gz] = accel;
+
+    let roll = Angle::atan2(gy, gz);
+    let (sin_roll, cos_roll) = roll.sin_cos();
+
+    let pitch = Angle::atan2(-gx, gy * sin_roll + gz * cos_roll);
+    let (sin_pitch, cos_pitch) = pitch.sin_cos();
+
+    // magnetic field rotated back into the horizontal plane
+    let north = bx * cos_pitch + (by * sin_roll + bz * cos_roll) * sin_pitch;
+    let east = bz * sin_roll - by * cos_roll;
+
+    Attitude {
+        roll,
+        pitch,
+        heading: Bearing::radians(east.atan2(north)).to_angle(),
+    }
+}
+
+/// Solves the linear system given as augmented matrix by Gaussian
+/// elimination with partial pivoting.
+fn solve<const N: usize, const M: usize>(mut matrix: [[f64; M]; N]) -> Option<[f64; N]> {
+    // pivots this much smaller than the largest entry mean a singular system
+    let tolerance = matrix
+        .iter()
+        .flatten()
+        .fold(0.0, |max: f64, value| max.max(fabs(*value)))
+        * 1e-12;
+
+    for column in 0..N {
+        let pivot = (column..N)
+            .max_by(|a, b| fabs(matrix[*a][column]).total_cmp(&fabs(matrix[*b][column])))?;
+
+        if fabs(matrix[pivot][column]).is_nan() || fabs(matrix[pivot][column]) <= tolerance {
+            return None;
+        }
+
+        matrix.swap(column, pivot);
+
+        let pivot = matrix[column];
+
+        for row in matrix.iter_mut().skip(column + 1) {
+            let factor = row[column] / pivot[column];
+
+            for (value, pivot) in row.iter_mut().zip(pivot).skip(column) {
+                *value -= factor * pivot;
+            }
+        }
+    }
+
+    let mut solution = [0.0; N];
+
+    for row in (0..N).rev() {
+        let sum: f64 = (row + 1..N).map(|k| matrix[row][k] * solution[k]).sum();
+
+        solution[row] = (matrix[row][N] - sum) / matrix[row][row];
+    }
+
+    Some(solution)
+}
+
+fn invert(m: [[f64; 3]; 3]) -> Option<[[f64; 3]; 3]> {
+    let columns = [0, 1, 2].map(|c| {
+        let mut augmented = [[0.0; 4]; 3];
+
+        for r in 0..3 {
+            augmented[r][..3].copy_from_slice(&m[r]);
+            augmented[r][3] = IDENTITY[r][c];
+        }
+
+        solve(augmented)
+    });
+
+    let [Some(a), Some(b), Some(c)] = columns else {
+        return None;
+    };
+
+    Some([0, 1, 2].map(|r| [a[r], b[r], c[r]]))
+}
+
+/// Eigenvalues and eigenvectors (as columns) of a symmetric matrix by Jacobi
+/// rotations.
+fn eigen(mut m: [[f64; 3]; 3]) -> ([f64; 3], [[f64; 3]; 3]) {
+    let mut vectors = IDENTITY;
+
+    for _ in 0..32 {
+        let off = m[0][1] * m[0][1] + m[0][2] * m[0][2] + m[1][2] * m[1][2];
+        let diagonal = m[0][0] * m[0][0] + m[1][1] * m[1][1] + m[2][2] * m[2][2];
+
+        if off <= 1e-30 * diagonal {
+            break;
+        }
+
+        for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+            if m[p][q] == 0.0 {
+                continue;
+            }
+
+            let theta = (m[q][q] - m[p][p]) / (2.0 * m[p][q]);
+            let t = theta.signum() / (fabs(theta) + sqrt(theta * theta + 1.0));
+            let c = 1.0 / sqrt(t * t + 1.0);
+            let s = t * c;
+
+            for row in m.iter_mut() {
+                let (mp, mq) = (row[p], row[q]);
+
+                row[p] = c * mp - s * mq;
+                row[q] = s * mp + c * mq;
+            }
+
+            let (mp, mq) = (m[p], m[q]);
+
+            m[p] = [0, 1, 2].map(|k| c * mp[k] - s * mq[k]);
+            m[q] = [0, 1, 2].map(|k| s * mp[k] + c * mq[k]);
+
+            for row in vectors.iter_mut() {
+                let (vp, vq) = (row[p], row[q]);
+
+                row[p] = c * vp - s * vq;
+                row[q] = s * vp + c * vq;
+            }
+        }
+    }
+
+    ([m[0][0], m[1][1], m[2][2]], vectors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use libm::{cos, sin};
+
+    /// Readings of a sensor with given attitude in a field with 60deg dip.
+    fn readings(roll: f64, pitch: f64, heading: f64) -> ([f64; 3], [f64; 3]) {
+        let (roll, pitch, heading) = (roll.to_radians(), pitch.to_radians(), heading.to_radians());
+        let dip = 60f64.to_radians();
+
+        // world (north, east, down) to body rotation, yaw then pitch then roll
+        let to_body = |[n, e, d]: [f64; 3]| {
+            let (x, y) = (
+                cos(heading) * n + sin(heading) * e,
+                -sin(heading) * n + cos(heading) * e,
+            );
+            let (x, z) = (
+                cos(pitch) * x - sin(pitch) * d,
+                sin(pitch) * x + cos(pitch) * d,
+            );
+            let (y, z) = (
+                cos(roll) * y + sin(roll) * z,
+                -sin(roll) * y + cos(roll) * z,
+            );
+
+            [x, y, z]
+        };
+
+        (
+            to_body([50.0 * cos(dip), 0.0, 50.0 * sin(dip)]),
+            to_body([0.0, 0.0, 9.81]),
+        )
+    }
+
+    #[test]
+    fn level() {
+        let (mag, accel) = readings(0.0, 0.0, 30.0);
+        let attitude = tilt_compensated(mag, accel, &Calibration::default());
+
+        assert!(attitude
+            .roll()
+            .is_within(&Angle::ZERO, Angle::degrees(0.001)));
+        assert!(attitude
+            .pitch()
+            .is_within(&Angle::ZERO, Angle::degrees(0.001)));
+        assert!(attitude
+            .bearing()
+            .is_within(&Bearing::degrees(30.0), Angle::degrees(0.001)));
+        assert!(attitude
+            .heading()
+            .is_within(&Angle::degrees(60.0), Angle::degrees(0.001)));
+    }
+
+    #[test]
+    fn tilted() {
+        for (roll, pitch, heading) in [
+            (20.0, -10.0, 135.0),
+            (-35.0, 40.0, 280.0),
+            (170.0, 5.0, 10.0),
+        ] {
+            let (mag, accel) = readings(roll, pitch, heading);
+            let attitude = tilt_compensated(mag, accel, &Calibration::default());
+
+            assert!(attitude
+                .roll()
+                .is_within(&Angle::degrees(roll), Angle::degrees(0.001)));
+            assert!(attitude
+                .pitch()
+                .is_within(&Angle::degrees(pitch), Angle::degrees(0.001)));
+            assert!(attitude
+                .bearing()
+                .is_within(&Bearing::degrees(heading), Angle::degrees(0.001)));
+        }
+
+        let (mag, accel) = readings(20.0, -10.0, 135.0);
+        let attitude = tilt_compensated(
+            mag.map(|v| v as f32),
+            accel.map(|v| v as f32),
+            &Calibration::default(),
+        );
+
+        assert!(attitude
+            .bearing()
+            .is_within(&Bearing::degrees(135.0), Angle::degrees(0.01)));
+    }
+
+    #[test]
+    fn calibration() {
+        let distortion = [[1.2, 0.1, 0.0], [0.1, 0.9, 0.05], [0.0, 0.05, 1.1]];
+        let mut samples = [[0.0; 3]; 64];
+
+        // field is 50 units strong, larger offsets put the origin outside of
+        // the sampled ellipsoid
+        for offset in [
+            [12.0, -30.0, 5.0],
+            [40.0, -30.0, 5.0],
+            [60.0, 0.0, 0.0],
+            [120.0, -30.0, 5.0],
+            [300.0, 100.0, -50.0],
+        ] {
+            let distort = |v: [f64; 3]| {
+                [0, 1, 2].map(|r| (0..3).map(|k| distortion[r][k] * v[k]).sum::<f64>() + offset[r])
+            };
+
+            for (i, sample) in samples.iter_mut().enumerate() {
+                let (roll, pitch, heading) = (
+                    (i % 4) as f64 * 90.0,
+                    (i / 16) as f64 * 50.0 - 75.0,
+                    (i % 16) as f64 * 22.5,
+                );
+
+                *sample = distort(readings(roll, pitch, heading).0);
+            }
+
+            let calibration = Calibration::fit(&samples).unwrap();
+
+            for (fitted, expected) in calibration.offset().iter().zip(offset) {
+                assert!(fabs(fitted - expected) < 1e-6);
+            }
+
+            for (roll, pitch, heading) in [(20.0, -10.0, 135.0), (-35.0, 40.0, 280.0)] {
+                let (mag, accel) = readings(roll, pitch, heading);
+                let attitude = tilt_compensated(distort(mag), accel, &calibration);
+
+                assert!(attitude
+                    .bearing()
+                    .is_within(&Bearing::degrees(heading), Angle::degrees(0.001)));
+            }
+        }
+
+        assert!(Calibration::<f64>::fit(&samples[..8]).is_none());
+
+        // only rotated around the vertical axis, so all samples lie on a circle
+        let level: [[f64; 3]; 16] = core::array::from_fn(|i| readings(0.0, 0.0, i as f64 * 22.5).0);
+
+        assert!(Calibration::fit(&level).is_none());
+    }
+}
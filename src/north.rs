@@ -0,0 +1,389 @@
+//! True, magnetic and grid north.
+//!
+//! [`Corrections`] converts headings between the three references, given
+//! the magnetic declination and grid convergence at the location.
+//! [`declination`] estimates the former offline from an embedded copy of the
+//! World Magnetic Model, [`transverse_mercator_convergence`] the latter for
+//! UTM-like grids.
+
+use crate::{Angle, Bearing, Scalar};
+use libm::{asin, atan2, cos, sin, sqrt};
+
+/// Reference direction a heading is measured from.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "use_defmt", derive(defmt::Format))]
+pub enum North {
+    /// Geographic north pole.
+    True,
+    /// Horizontal direction of the magnetic field.
+    Magnetic,
+    /// Northing axis of a map grid.
+    Grid,
+}
+
+/// Directions of magnetic and grid north at some location, both measured
+/// clockwise (east positive) from true north.
+#[derive(Copy, Clone, Debug)]
+pub struct Corrections<T = f64> {
+    declination: Angle<T>,
+    convergence: Angle<T>,
+}
+
+impl<T: Scalar> Corrections<T> {
+    pub fn new(declination: Angle<T>, convergence: Angle<T>) -> Self {
+        Self {
+            declination,
+            convergence,
+        }
+    }
+
+    pub fn declination(&self) -> Angle<T> {
+        self.declination
+    }
+
+    pub fn convergence(&self) -> Angle<T> {
+        self.convergence
+    }
+
+    /// Converts a bearing measured from `from` north into one measured from
+    /// `to` north, e.g. magnetic into true bearing by adding declination.
+    pub fn bearing(&self, bearing: Bearing<T>, from: North, to: North) -> Bearing<T> {
+        let shift = self.offset(from) - self.offset(to);
+
+        Bearing::radians(bearing.as_radians() + shift.as_radians())
+    }
+
+    /// Same as [`Corrections::bearing`] for headings in math convention
+    /// (counter-clockwise), so the corrections apply with opposite sign.
+    pub fn angle(&self, angle: Angle<T>, from: North, to: North) -> Angle<T> {
+        angle - (self.offset(from) - self.offset(to))
+    }
+
+    fn offset(&self, north: North) -> Angle<T> {
+        match north {
+            North::True => Angle::ZERO,
+            North::Magnetic => self.declination,
+            North::Grid => self.convergence,
+        }
+    }
+}
+
+/// Grid convergence of a transverse Mercator projection such as UTM, i.e.
+/// direction of grid north measured clockwise from true north.
+///
+/// Uses the spherical approximation, which is within a few arcseconds of the
+/// ellipsoidal value inside a UTM zone.
+pub fn transverse_mercator_convergence<T: Scalar>(
+    latitude: Angle<T>,
+    longitude: Angle<T>,
+    central_meridian: Angle<T>,
+) -> Angle<T> {
+    let (sin_delta, cos_delta) = (longitude - central_meridian).sin_cos();
+
+    Angle::atan2(sin_delta * latitude.sin(), cos_delta)
+}
+
+/// Fractional year at the start of given day, as expected by [`declination`].
+/// Month and day are counted from 1.
+pub fn decimal_year<T: Scalar>(year: u16, month: u8, day: u8) -> T {
+    const DAYS_BEFORE: [u16; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+
+    let leap = year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400));
+    let month = month.clamp(1, 12) as usize;
+
+    let day = DAYS_BEFORE[month - 1] + u16::from(day.max(1)) - 1;
+    let day = if leap && month > 2 { day + 1 } else { day };
+    let length = if leap { 366.0 } else { 365.0 };
+
+    T::from_f64(year as f64 + day as f64 / length)
+}
+
+/// Epoch of the embedded model, coefficients drift linearly from it.
+const EPOCH: f64 = 2025.0;
+
+/// WGS 84 semi-major axis in km.
+const WGS84_A: f64 = 6378.137;
+
+/// WGS 84 first eccentricity squared.
+const WGS84_E2: f64 = 0.0066943799901413165;
+
+/// Geomagnetic reference radius in km.
+const REFERENCE_RADIUS: f64 = 6371.2;
+
+/// Highest degree of the spherical harmonic expansion.
+const DEGREE: usize = 12;
+
+/// World Magnetic Model 2025 Gauss coefficients as `(n, m, g, h, g_dot,
+/// h_dot)`, main field in nT and secular variation in nT per year.
+#[rustfmt::skip]
+const COEFFICIENTS: [(u8, u8, f32, f32, f32, f32); DEGREE * (DEGREE + 3) / 2] = [
+    (1, 0, -29351.8, 0.0, 12.0, 0.0),
+    (1, 1, -1410.8, 4545.4, 9.7, -21.5),
+    (2, 0, -2556.6, 0.0, -11.6, 0.0),
+    (2, 1, 2951.1, -3133.6, -5.2, -27.7),
+    (2, 2, 1649.3, -815.1, -8.0, -12.1),
+    (3, 0, 1361.0, 0.0, -1.3, 0.0),
+    (3, 1, -2404.1, -56.6, -4.2, 4.0),
+    (3, 2, 1243.8, 237.5, 0.4, -0.3),
+    (3, 3, 453.6, -549.5, -15.6, -4.1),
+    (4, 0, 895.0, 0.0, -1.6, 0.0),
+    (4, 1, 799.5, 278.6, -2.4, -1.1),
+    (4, 2, 55.7, -133.9, -6.0, 4.1),
+    (4, 3, -281.1, 212.0, 5.6, 1.6),
+    (4, 4, 12.1, -375.6, -7.0, -4.4),
+    (5, 0, -233.2, 0.0, 0.6, 0.0),
+    (5, 1, 368.9, 45.4, 1.4, -0.5),
+    (5, 2, 187.2, 220.2, 0.0, 2.2),
+    (5, 3, -138.7, -122.9, 0.6, 0.4),
+    (5, 4, -142.0, 43.0, 2.2, 1.7),
+    (5, 5, 20.9, 106.1, 0.9, 1.9),
+    (6, 0, 64.4, 0.0, -0.2, 0.0),
+    (6, 1, 63.8, -18.4, -0.4, 0.3),
+    (6, 2, 76.9, 16.8, 0.9, -1.6),
+    (6, 3, -115.7, 48.8, 1.2, -0.4),
+    (6, 4, -40.9, -59.8, -0.9, 0.9),
+    (6, 5, 14.9, 10.9, 0.3, 0.7),
+    (6, 6, -60.7, 72.7, 0.9, 0.9),
+    (7, 0, 79.5, 0.0, 0.0, 0.0),
+    (7, 1, -77.0, -48.9, -0.1, 0.6),
+    (7, 2, -8.8, -14.4, -0.1, 0.5),
+    (7, 3, 59.3, -1.0, 0.5, -0.8),
+    (7, 4, 15.8, 23.4, -0.1, 0.0),
+    (7, 5, 2.5, -7.4, -0.8, -1.0),
+    (7, 6, -11.1, -25.1, -0.8, 0.6),
+    (7, 7, 14.2, -2.3, 0.8, -0.2),
+    (8, 0, 23.2, 0.0, -0.1, 0.0),
+    (8, 1, 10.8, 7.1, 0.2, -0.2),
+    (8, 2, -17.5, -12.6, 0.0, 0.5),
+    (8, 3, 2.0, 11.4, 0.5, -0.4),
+    (8, 4, -21.7, -9.7, -0.1, 0.4),
+    (8, 5, 16.9, 12.7, 0.3, -0.5),
+    (8, 6, 15.0, 0.7, 0.2, -0.6),
+    (8, 7, -16.8, -5.2, 0.0, 0.3),
+    (8, 8, 0.9, 3.9, 0.2, 0.2),
+    (9, 0, 4.6, 0.0, 0.0, 0.0),
+    (9, 1, 7.8, -24.8, -0.1, -0.3),
+    (9, 2, 3.0, 12.2, 0.1, 0.3),
+    (9, 3, -0.2, 8.3, 0.3, -0.3),
+    (9, 4, -2.5, -3.3, -0.3, 0.3),
+    (9, 5, -13.1, -5.2, 0.0, 0.2),
+    (9, 6, 2.4, 7.2, 0.3, -0.1),
+    (9, 7, 8.6, -0.6, -0.1, -0.2),
+    (9, 8, -8.7, 0.8, 0.1, 0.4),
+    (9, 9, -12.9, 10.0, -0.1, 0.1),
+    (10, 0, -1.3, 0.0, 0.1, 0.0),
+    (10, 1, -6.4, 3.3, 0.0, 0.0),
+    (10, 2, 0.2, 0.0, 0.1, 0.0),
+    (10, 3, 2.0, 2.4, 0.1, -0.2),
+    (10, 4, -1.0, 5.3, 0.0, 0.1),
+    (10, 5, -0.6, -9.1, -0.3, -0.1),
+    (10, 6, -0.9, 0.4, 0.0, 0.1),
+    (10, 7, 1.5, -4.2, -0.1, 0.0),
+    (10, 8, 0.9, -3.8, -0.1, -0.1),
+    (10, 9, -2.7, 0.9, 0.0, 0.2),
+    (10, 10, -3.9, -9.1, 0.0, 0.0),
+    (11, 0, 2.9, 0.0, 0.0, 0.0),
+    (11, 1, -1.5, 0.0, 0.0, 0.0),
+    (11, 2, -2.5, 2.9, 0.0, 0.1),
+    (11, 3, 2.4, -0.6, 0.0, 0.0),
+    (11, 4, -0.6, 0.2, 0.0, 0.1),
+    (11, 5, -0.1, 0.5, -0.1, 0.0),
+    (11, 6, -0.6, -0.3, 0.0, 0.0),
+    (11, 7, -0.1, -1.2, 0.0, 0.1),
+    (11, 8, 1.1, -1.7, -0.1, 0.0),
+    (11, 9, -1.0, -2.9, -0.1, 0.0),
+    (11, 10, -0.2, -1.8, -0.1, 0.0),
+    (11, 11, 2.6, -2.3, -0.1, 0.0),
+    (12, 0, -2.0, 0.0, 0.0, 0.0),
+    (12, 1, -0.2, -1.3, 0.0, 0.0),
+    (12, 2, 0.3, 0.7, 0.0, 0.0),
+    (12, 3, 1.2, 1.0, 0.0, -0.1),
+    (12, 4, -1.3, -1.4, 0.0, 0.1),
+    (12, 5, 0.6, 0.0, 0.0, 0.0),
+    (12, 6, 0.6, 0.6, 0.1, 0.0),
+    (12, 7, 0.5, -0.1, 0.0, 0.0),
+    (12, 8, -0.1, 0.8, 0.0, 0.0),
+    (12, 9, -0.4, 0.1, 0.0, 0.0),
+    (12, 10, -0.2, -1.0, -0.1, 0.0),
+    (12, 11, -1.3, 0.1, 0.0, 0.0),
+    (12, 12, -0.7, 0.2, -0.1, -0.1),
+];
+
+/// Magnetic declination, i.e. direction of magnetic north measured clockwise
+/// from true north, according to the World Magnetic Model 2025.
+///
+/// Takes WGS 84 geodetic `latitude` and `longitude`, `altitude` in meters
+/// above the ellipsoid and date as fractional `year` (see [`decimal_year`]).
+/// The model is valid from 2025 to 2030, outside of that its linear drift is
+/// extrapolated and accuracy quickly degrades. Declination is meaningless
+/// close to the geographic and magnetic poles.
+pub fn declination<T: Scalar>(
+    latitude: Angle<T>,
+    longitude: Angle<T>,
+    altitude: T,
+    year: T,
+) -> Angle<T> {
+    let latitude = latitude.as_radians().to_f64();
+    let longitude = longitude.as_radians().to_f64();
+    let altitude = altitude.to_f64() / 1000.0;
+    let years = year.to_f64() - EPOCH;
+
+    // geodetic to geocentric spherical coordinates
+    let radius = WGS84_A / sqrt(1.0 - WGS84_E2 * sin(latitude) * sin(latitude));
+    let p = (radius + altitude) * cos(latitude);
+    let z = (radius * (1.0 - WGS84_E2) + altitude) * sin(latitude);
+    let r = sqrt(p * p + z * z);
+    let geocentric = asin(z / r);
+
+    // cosine and sine of colatitude
+    let (ct, st) = (sin(geocentric), cos(geocentric));
+
+    // Gauss normalized associated Legendre functions, their derivatives
+    // by colatitude and factors to Schmidt semi-normalization
+    let mut pnm = [[0.0; DEGREE + 1]; DEGREE + 1];
+    let mut dpnm = [[0.0; DEGREE + 1]; DEGREE + 1];
+    let mut schmidt = [[0.0; DEGREE + 1]; DEGREE + 1];
+
+    pnm[0][0] = 1.0;
+    schmidt[0][0] = 1.0;
+
+    for n in 1..=DEGREE {
+        schmidt[n][0] = schmidt[n - 1][0] * (2 * n - 1) as f64 / n as f64;
+
+        for m in 0..=n {
+            if m > 0 {
+                let factor = if m == 1 { 2.0 } else { 1.0 };
+
+                schmidt[n][m] =
+                    schmidt[n][m - 1] * sqrt((n - m + 1) as f64 * factor / (n + m) as f64);
+            }
+
+            if n == m {
+                pnm[n][m] = st * pnm[n - 1][m - 1];
+                dpnm[n][m] = st * dpnm[n - 1][m - 1] + ct * pnm[n - 1][m - 1];
+            } else if n == 1 {
+                pnm[n][m] = ct * pnm[0][0];
+                dpnm[n][m] = ct * dpnm[0][0] - st * pnm[0][0];
+            } else {
+                let k = ((n - 1) * (n - 1) - m * m) as f64 / ((2 * n - 1) * (2 * n - 3)) as f64;
+
+                pnm[n][m] = ct * pnm[n - 1][m] - k * pnm[n - 2][m];
+                dpnm[n][m] = ct * dpnm[n - 1][m] - st * pnm[n - 1][m] - k * dpnm[n - 2][m];
+            }
+        }
+    }
+
+    // field components in geocentric north, east and down directions
+    let (mut north, mut east, mut down) = (0.0, 0.0, 0.0);
+    let mut ratio = [0.0; DEGREE + 1];
+
+    ratio[0] = (REFERENCE_RADIUS / r) * (REFERENCE_RADIUS / r);
+
+    for n in 1..=DEGREE {
+        ratio[n] = ratio[n - 1] * REFERENCE_RADIUS / r;
+    }
+
+    for (n, m, g, h, g_dot, h_dot) in COEFFICIENTS {
+        let (n, m) = (n as usize, m as usize);
+
+        let g = (g as f64 + g_dot as f64 * years) * schmidt[n][m];
+        let h = (h as f64 + h_dot as f64 * years) * schmidt[n][m];
+        let (sin_m, cos_m) = (sin(m as f64 * longitude), cos(m as f64 * longitude));
+        let along = g * cos_m + h * sin_m;
+
+        north += ratio[n] * along * dpnm[n][m];
+        east += ratio[n] * m as f64 * (g * sin_m - h * cos_m) * pnm[n][m];
+        down -= ratio[n] * (n + 1) as f64 * along * pnm[n][m];
+    }
+
+    // east component is undefined at the geographic poles, where it is zero
+    // anyway
+    let east = if st > 1e-12 { east / st } else { 0.0 };
+
+    // north component rotated back into geodetic frame
+    let tilt = geocentric - latitude;
+    let north = north * cos(tilt) - down * sin(tilt);
+
+    Angle::radians(T::from_f64(atan2(east, north)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn corrections() {
+        let corrections = Corrections::new(Angle::degrees(15.0), Angle::degrees(-2.0));
+        let magnetic = Bearing::degrees(350.0);
+
+        assert!(corrections
+            .bearing(magnetic, North::Magnetic, North::True)
+            .is_within(&Bearing::degrees(5.0), Angle::degrees(0.001)));
+        assert!(corrections
+            .bearing(magnetic, North::Magnetic, North::Grid)
+            .is_within(&Bearing::degrees(7.0), Angle::degrees(0.001)));
+        assert!(corrections
+            .bearing(Bearing::degrees(7.0), North::Grid, North::Magnetic)
+            .is_within(&magnetic, Angle::degrees(0.001)));
+
+        let angle = corrections.angle(magnetic.to_angle(), North::Magnetic, North::True);
+
+        assert!(Bearing::from_angle(angle).is_within(&Bearing::degrees(5.0), Angle::degrees(0.001)));
+    }
+
+    #[test]
+    fn convergence() {
+        let convergence = transverse_mercator_convergence(
+            Angle::degrees(45.0),
+            Angle::degrees(12.0),
+            Angle::degrees(9.0),
+        );
+
+        assert!(convergence.is_within(&Angle::degrees(2.12225), Angle::degrees(0.001)));
+        assert!(transverse_mercator_convergence(
+            Angle::<f32>::degrees(-45.0),
+            Angle::degrees(12.0),
+            Angle::degrees(9.0)
+        )
+        .is_within(&Angle::degrees(-2.12225), Angle::degrees(0.001)));
+    }
+
+    #[test]
+    fn year() {
+        assert_eq!(decimal_year::<f64>(2026, 1, 1), 2026.0);
+        assert_eq!(decimal_year::<f64>(2026, 7, 2), 2026.0 + 182.0 / 365.0);
+        assert_eq!(decimal_year::<f64>(2028, 7, 2), 2028.0 + 183.0 / 366.0);
+    }
+
+    #[test]
+    fn declination_model() {
+        // reference values from the full model
+        let cases = [
+            (47.6, -122.3, 0.0, 2026.27, 14.926),
+            (-33.9, 151.2, 0.0, 2027.54, 12.853),
+            (35.7, 139.7, 1000.0, 2028.13, -7.989),
+            (-22.9, -43.2, 0.0, 2029.82, -23.069),
+            (-60.0, 120.0, 0.0, 2026.0, -61.501),
+        ];
+
+        for (latitude, longitude, altitude, year, expected) in cases {
+            let declination = declination(
+                Angle::degrees(latitude),
+                Angle::degrees(longitude),
+                altitude,
+                year,
+            );
+
+            assert!(declination.is_within(&Angle::degrees(expected), Angle::degrees(0.05)));
+        }
+
+        let london = declination(
+            Angle::<f32>::degrees(51.5),
+            Angle::degrees(-0.1),
+            50.0,
+            decimal_year(2025, 1, 1),
+        );
+
+        assert!(london.is_within(&Angle::degrees(0.916), Angle::degrees(0.05)));
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{Angle, ParseAngleError, Scalar, Unit};
+use core::str::FromStr;
+
+impl<T: Scalar> Angle<T> {
+    /// Parses a number followed by optional unit suffix (see
+    /// [`Unit::from_suffix`]), using `default` for plain numbers.
+    pub fn parse_with_default(s: &str, default: Unit) -> Result<Self, ParseAngleError> {
+        let s = s.trim();
+
+        let split = s
+            .find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '+' | '-' | 'e' | 'E')))
+            .unwrap_or(s.len());
+
+        let (number, suffix) = s.split_at(split);
+        let suffix = suffix.trim_start();
+
+        let unit = if suffix.is_empty() {
+            default
+        } else {
+            Unit::from_suffix(suffix).ok_or(ParseAngleError::UnknownUnit)?
+        };
+
+        let value = number.parse().map_err(|_| ParseAngleError::InvalidNumber)?;
+
+        Self::check(value)
+            .map(|value| Self::from_unit(value, unit))
+            .map_err(ParseAngleError::InvalidValue)
+    }
+}
+
+/// Accepts what `Display` writes, e.g. `"90deg"`, as well as other units like
+/// `"1.57rad"`, `"0.25turn"`, `"100grad"` or `"90°"`. Plain numbers are
+/// taken as degrees, use [`Angle::parse_with_default`] to change that.
+impl<T: Scalar> FromStr for Angle<T> {
+    type Err = ParseAngleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with_default(s, Unit::Degrees)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AngleError;
+
+    #[test]
+    fn suffixes() {
+        assert_eq!("90deg".parse(), Ok(Angle::degrees(90.0)));
+        assert_eq!("-90 deg".parse(), Ok(Angle::degrees(-90.0)));
+        assert_eq!("1.57rad".parse(), Ok(Angle::radians(1.57)));
+        assert_eq!("0.25turn".parse(), Ok(Angle::turns(0.25)));
+        assert_eq!("100grad".parse(), Ok(Angle::degrees(90.0)));
+        assert_eq!("90°".parse(), Ok(Angle::degrees(90.0)));
+        assert_eq!(" 1600 MIL ".parse(), Ok(Angle::degrees(90.0)));
+        assert_eq!("5400'".parse(), Ok(Angle::degrees(90.0)));
+        assert_eq!("1e2grad".parse(), Ok(Angle::degrees(90.0)));
+        assert_eq!("90deg".parse(), Ok(Angle::<f32>::degrees(90.0)));
+    }
+
+    #[test]
+    fn default_unit() {
+        assert_eq!("90".parse(), Ok(Angle::degrees(90.0)));
+        assert_eq!(
+            Angle::parse_with_default("0.5", Unit::Turns),
+            Ok(Angle::degrees(180.0))
+        );
+        assert_eq!(
+            Angle::parse_with_default("2rad", Unit::Turns),
+            Ok(Angle::radians(2.0))
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!("".parse::<Angle>(), Err(ParseAngleError::InvalidNumber));
+        assert_eq!("deg".parse::<Angle>(), Err(ParseAngleError::InvalidNumber));
+        assert_eq!(
+            "1.2.3deg".parse::<Angle>(),
+            Err(ParseAngleError::InvalidNumber)
+        );
+        assert_eq!("NaN".parse::<Angle>(), Err(ParseAngleError::UnknownUnit));
+        assert_eq!(
+            "90 degs".parse::<Angle>(),
+            Err(ParseAngleError::UnknownUnit)
+        );
+        assert_eq!(
+            "1e999rad".parse::<Angle>(),
+            Err(ParseAngleError::InvalidValue(AngleError::Infinite))
+        );
+    }
+
+    #[test]
+    fn display_round_trip() {
+        extern crate std;
+        use std::string::ToString;
+
+        for degrees in [-179.5, -90.0, 0.0, 12.345, 90.0, 180.0] {
+            let angle = Angle::degrees(degrees);
+            let parsed: Angle = angle.to_string().parse().unwrap();
+
+            assert_eq!(parsed, angle);
+        }
+
+        // -177.61332404517972deg used to come back one ulp off
+        for i in 0..100_000 {
+            let angle = Angle::degrees(-180.0 + i as f64 * 0.0036000000123);
+            let parsed: Angle = angle.to_string().parse().unwrap();
+
+            assert_eq!(parsed, angle);
+
+            let angle = Angle::<f32>::degrees(i as f32 * 0.0036);
+            let parsed: Angle<f32> = angle.to_string().parse().unwrap();
+
+            assert_eq!(parsed, angle);
+        }
+
+        for unit in [
+            Unit::Gradians,
+            Unit::ArcMinutes,
+            Unit::ArcSeconds,
+            Unit::Mils,
+        ] {
+            for i in 0..1000 {
+                let angle = Angle::from_unit(i as f64 * 1.2345678901, unit);
+                let parsed: Angle = angle.display(unit).to_string().parse().unwrap();
+
+                assert_eq!(parsed, angle);
+            }
+        }
+
+        let angle = Angle::degrees(-177.61332404517972);
+        assert_eq!(angle.to_string().parse::<Angle>(), Ok(angle));
+    }
+}
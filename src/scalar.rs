@@ -0,0 +1,164 @@
+use core::cmp::Ordering;
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use core::str::FromStr;
+
+/// Numeric backend used to store the value of an [`Angle`](crate::Angle).
+///
+/// Implemented for `f32` and `f64`. The `f32` implementation uses the
+/// single precision `libm` functions (`sinf`, `cosf`, `fabsf`, ...), so it
+/// stays on the FPU of targets that only accelerate single precision math.
+/// The trait is public, so other backends (e.g. a fixed-point type) can be
+/// plugged in by implementing it.
+pub trait Scalar:
+    Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+    + FromStr
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const PI: Self;
+    const TAU: Self;
+    const FRAC_PI_2: Self;
+    const EPSILON: Self;
+
+    fn from_f64(value: f64) -> Self;
+
+    fn to_f64(self) -> f64;
+
+    fn to_f32(self) -> f32;
+
+    fn to_bits(self) -> u64;
+
+    fn is_nan(self) -> bool;
+
+    fn is_infinite(self) -> bool;
+
+    fn total_cmp(&self, other: &Self) -> Ordering;
+
+    fn to_radians(self) -> Self;
+
+    fn to_degrees(self) -> Self;
+
+    fn abs(self) -> Self;
+
+    fn sqrt(self) -> Self;
+
+    fn ln(self) -> Self;
+
+    fn sin(self) -> Self;
+
+    fn cos(self) -> Self;
+
+    fn tan(self) -> Self;
+
+    fn asin(self) -> Self;
+
+    fn acos(self) -> Self;
+
+    fn atan2(self, other: Self) -> Self;
+}
+
+macro_rules! impl_scalar {
+    (
+        $t:ident,
+        $fabs:ident,
+        $sqrt:ident,
+        $ln:ident,
+        $sin:ident,
+        $cos:ident,
+        $tan:ident,
+        $asin:ident,
+        $acos:ident,
+        $atan2:ident
+    ) => {
+        impl Scalar for $t {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const PI: Self = core::$t::consts::PI;
+            const TAU: Self = core::$t::consts::TAU;
+            const FRAC_PI_2: Self = core::$t::consts::FRAC_PI_2;
+            const EPSILON: Self = $t::EPSILON;
+
+            fn from_f64(value: f64) -> Self {
+                value as $t
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn to_f32(self) -> f32 {
+                self as f32
+            }
+
+            fn to_bits(self) -> u64 {
+                $t::to_bits(self) as u64
+            }
+
+            fn is_nan(self) -> bool {
+                $t::is_nan(self)
+            }
+
+            fn is_infinite(self) -> bool {
+                $t::is_infinite(self)
+            }
+
+            fn total_cmp(&self, other: &Self) -> Ordering {
+                $t::total_cmp(self, other)
+            }
+
+            fn to_radians(self) -> Self {
+                $t::to_radians(self)
+            }
+
+            fn to_degrees(self) -> Self {
+                $t::to_degrees(self)
+            }
+
+            fn abs(self) -> Self {
+                libm::$fabs(self)
+            }
+
+            fn sqrt(self) -> Self {
+                libm::$sqrt(self)
+            }
+
+            fn ln(self) -> Self {
+                libm::$ln(self)
+            }
+
+            fn sin(self) -> Self {
+                libm::$sin(self)
+            }
+
+            fn cos(self) -> Self {
+                libm::$cos(self)
+            }
+
+            fn tan(self) -> Self {
+                libm::$tan(self)
+            }
+
+            fn asin(self) -> Self {
+                libm::$asin(self)
+            }
+
+            fn acos(self) -> Self {
+                libm::$acos(self)
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                libm::$atan2(self, other)
+            }
+        }
+    };
+}
+
+impl_scalar!(f32, fabsf, sqrtf, logf, sinf, cosf, tanf, asinf, acosf, atan2f);
+impl_scalar!(f64, fabs, sqrt, log, sin, cos, tan, asin, acos, atan2);
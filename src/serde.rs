@@ -0,0 +1,234 @@
+//! Serde support, enabled with `use_serde` feature.
+//!
+//! [`Angle`] itself is serialized as plain number of radians. The submodules
+//! select another representation with `#[serde(with = "...")]`:
+//!
+//! - [`degrees`] - plain number of degrees,
+//! - [`radians`] - plain number of radians, same as the default,
+//! - [`tagged`] - single entry map with unit suffix as key, e.g. `{"deg": 90.0}`.
+//!
+//! Deserialization always normalizes the value and rejects NaN and infinity.
+
+use crate::{Angle, Scalar, Unit};
+use ::serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use core::{fmt::Formatter, marker::PhantomData};
+
+impl<T: Scalar + Serialize> Serialize for Angle<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        radians::serialize(self, serializer)
+    }
+}
+
+impl<'de, T: Scalar + Deserialize<'de>> Deserialize<'de> for Angle<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        radians::deserialize(deserializer)
+    }
+}
+
+fn deserialize_unit<'de, T, D>(deserializer: D, unit: Unit) -> Result<Angle<T>, D::Error>
+where
+    T: Scalar + Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let value = T::deserialize(deserializer)?;
+
+    Angle::check(value)
+        .map(|value| Angle::from_unit(value, unit))
+        .map_err(de::Error::custom)
+}
+
+pub mod degrees {
+    use super::*;
+
+    pub fn serialize<T, S>(angle: &Angle<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Scalar + Serialize,
+        S: Serializer,
+    {
+        angle.as_degrees().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Angle<T>, D::Error>
+    where
+        T: Scalar + Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        deserialize_unit(deserializer, Unit::Degrees)
+    }
+}
+
+pub mod radians {
+    use super::*;
+
+    pub fn serialize<T, S>(angle: &Angle<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Scalar + Serialize,
+        S: Serializer,
+    {
+        angle.as_radians().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Angle<T>, D::Error>
+    where
+        T: Scalar + Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        deserialize_unit(deserializer, Unit::Radians)
+    }
+}
+
+/// Serializes as `{"deg": value}`. Deserialization accepts any unit suffix
+/// recognized by [`Unit::from_suffix`] as the key, e.g. `{"rad": 1.57}`.
+pub mod tagged {
+    use super::*;
+    use ::serde::ser::SerializeMap;
+
+    pub fn serialize<T, S>(angle: &Angle<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Scalar + Serialize,
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(Unit::Degrees.suffix(), &angle.as_degrees())?;
+        map.end()
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Angle<T>, D::Error>
+    where
+        T: Scalar + Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(TaggedVisitor(PhantomData))
+    }
+
+    struct UnitKey(Unit);
+
+    impl<'de> Deserialize<'de> for UnitKey {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_str(UnitKeyVisitor)
+        }
+    }
+
+    struct UnitKeyVisitor;
+
+    impl de::Visitor<'_> for UnitKeyVisitor {
+        type Value = UnitKey;
+
+        fn expecting(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            write!(f, "angle unit suffix")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            Unit::from_suffix(v)
+                .map(UnitKey)
+                .ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))
+        }
+    }
+
+    struct TaggedVisitor<T>(PhantomData<T>);
+
+    impl<'de, T: Scalar + Deserialize<'de>> de::Visitor<'de> for TaggedVisitor<T> {
+        type Value = Angle<T>;
+
+        fn expecting(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            write!(f, "map with single angle unit entry")
+        }
+
+        fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let UnitKey(unit) = map
+                .next_key()?
+                .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+            let value: T = map.next_value()?;
+
+            if map.next_key::<UnitKey>()?.is_some() {
+                return Err(de::Error::invalid_length(2, &self));
+            }
+
+            Angle::check(value)
+                .map(|value| Angle::from_unit(value, unit))
+                .map_err(de::Error::custom)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+    struct Pose {
+        heading: Angle,
+        #[serde(with = "degrees")]
+        pitch: Angle,
+        #[serde(with = "tagged")]
+        roll: Angle<f32>,
+    }
+
+    #[test]
+    fn round_trip() {
+        let pose = Pose {
+            heading: Angle::radians(1.5),
+            pitch: Angle::degrees(-10.0),
+            roll: Angle::degrees(90.0),
+        };
+
+        let json = serde_json::to_string(&pose).unwrap();
+
+        assert_eq!(json, r#"{"heading":1.5,"pitch":-10.0,"roll":{"deg":90.0}}"#);
+        assert_eq!(serde_json::from_str::<Pose>(&json).unwrap(), pose);
+    }
+
+    #[test]
+    fn normalize() {
+        let pose: Pose = serde_json::from_str(
+            r#"{"heading":-3.141592653589793,"pitch":450,"roll":{"turn":-0.75}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(pose.heading, Angle::radians(core::f64::consts::PI));
+        assert_eq!(pose.pitch, Angle::degrees(90.0));
+        assert_eq!(pose.roll, Angle::degrees(90.0));
+    }
+
+    #[test]
+    fn reject() {
+        assert!(serde_json::from_str::<Angle>("null").is_err());
+        assert!(serde_json::from_str::<Angle>("1e999").is_err());
+        assert!(
+            serde_json::from_str::<Pose>(r#"{"heading":0,"pitch":0,"roll":{"foo":1}}"#).is_err()
+        );
+        assert!(serde_json::from_str::<Pose>(
+            r#"{"heading":0,"pitch":0,"roll":{"deg":1,"rad":1}}"#
+        )
+        .is_err());
+        assert!(serde_json::from_str::<Pose>(r#"{"heading":0,"pitch":0,"roll":{}}"#).is_err());
+    }
+
+    #[test]
+    fn reject_non_finite() {
+        extern crate std;
+
+        use ::serde::de::{value::MapDeserializer, IntoDeserializer};
+        use std::string::ToString;
+
+        type Error = ::serde::de::value::Error;
+
+        for (value, message) in [
+            (f64::NAN, "angle value is NaN"),
+            (f64::INFINITY, "angle value is infinite"),
+            (f64::NEG_INFINITY, "angle value is infinite"),
+        ] {
+            let deserializer = IntoDeserializer::<Error>::into_deserializer(value);
+            let error = Angle::<f64>::deserialize(deserializer).unwrap_err();
+            assert_eq!(error.to_string(), message);
+
+            let deserializer = IntoDeserializer::<Error>::into_deserializer(value);
+            let error = degrees::deserialize::<f64, _>(deserializer).unwrap_err();
+            assert_eq!(error.to_string(), message);
+
+            let map = MapDeserializer::<_, Error>::new([("deg", value)].into_iter());
+            let error = tagged::deserialize::<f64, _>(map).unwrap_err();
+            assert_eq!(error.to_string(), message);
+        }
+    }
+}
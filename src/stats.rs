@@ -0,0 +1,355 @@
+//! Circular statistics.
+//!
+//! Angles are averaged as unit vectors, so the seam at `+-180deg` does not
+//! matter: the mean of `179deg` and `-179deg` is `180deg`. Everything is
+//! computed in a single pass over an iterator, without allocation.
+//!
+//! Robust statistics ([`median`], [`trimmed_mean`], [`reject_outliers`])
+//! work on slices instead, which may be reordered in place.
+
+use crate::{Angle, Scalar};
+
+/// Running sum of unit vectors, the base of all statistics in this module.
+///
+/// Can be fed sample by sample, e.g. from an interrupt handler, and queried at
+/// any time.
+#[derive(Copy, Clone, Debug)]
+pub struct Resultant<T = f64> {
+    sin: T,
+    cos: T,
+    weight: T,
+}
+
+impl<T: Scalar> Default for Resultant<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Scalar> Resultant<T> {
+    pub fn new() -> Self {
+        Self {
+            sin: T::ZERO,
+            cos: T::ZERO,
+            weight: T::ZERO,
+        }
+    }
+
+    pub fn add(&mut self, angle: Angle<T>) {
+        self.add_weighted(angle, T::ONE);
+    }
+
+    pub fn add_weighted(&mut self, angle: Angle<T>, weight: T) {
+        let (sin, cos) = angle.sin_cos();
+
+        self.sin = self.sin + sin * weight;
+        self.cos = self.cos + cos * weight;
+        self.weight = self.weight + weight;
+    }
+
+    /// Circular mean, `None` if there are no samples or they cancel each other
+    /// out (e.g. `0deg` and `180deg`).
+    pub fn mean(&self) -> Option<Angle<T>> {
+        if self.length() > T::EPSILON {
+            Some(Angle::atan2(self.sin, self.cos))
+        } else {
+            None
+        }
+    }
+
+    /// Mean resultant length `R` in `[0, 1]`: `1` for identical samples, close
+    /// to `0` for uniformly spread ones. `0` if there are no samples.
+    pub fn length(&self) -> T {
+        if self.weight > T::ZERO {
+            let length = (self.sin * self.sin + self.cos * self.cos).sqrt() / self.weight;
+
+            // rounding can push identical samples slightly above 1
+            if length > T::ONE {
+                T::ONE
+            } else {
+                length
+            }
+        } else {
+            T::ZERO
+        }
+    }
+
+    /// Circular variance `1 - R` in `[0, 1]`.
+    pub fn variance(&self) -> T {
+        T::ONE - self.length()
+    }
+
+    /// Circular standard deviation `sqrt(-2 ln R)` in radians, infinite for
+    /// `R = 0`.
+    pub fn std_dev(&self) -> T {
+        (T::from_f64(-2.0) * self.length().ln()).sqrt()
+    }
+
+    /// Angular deviation `sqrt(2 (1 - R))` in radians, within `[0, sqrt(2)]`.
+    pub fn angular_deviation(&self) -> T {
+        (T::from_f64(2.0) * self.variance()).sqrt()
+    }
+}
+
+impl<T: Scalar> Extend<Angle<T>> for Resultant<T> {
+    fn extend<I: IntoIterator<Item = Angle<T>>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|angle| self.add(angle));
+    }
+}
+
+impl<T: Scalar> FromIterator<Angle<T>> for Resultant<T> {
+    fn from_iter<I: IntoIterator<Item = Angle<T>>>(iter: I) -> Self {
+        let mut resultant = Self::new();
+        resultant.extend(iter);
+        resultant
+    }
+}
+
+/// See [`Resultant::mean`].
+pub fn mean<T: Scalar>(angles: impl IntoIterator<Item = Angle<T>>) -> Option<Angle<T>> {
+    angles.into_iter().collect::<Resultant<T>>().mean()
+}
+
+/// Circular mean of `(angle, weight)` pairs, see [`Resultant::mean`].
+pub fn weighted_mean<T: Scalar>(
+    angles: impl IntoIterator<Item = (Angle<T>, T)>,
+) -> Option<Angle<T>> {
+    let mut resultant = Resultant::new();
+
+    for (angle, weight) in angles {
+        resultant.add_weighted(angle, weight);
+    }
+
+    resultant.mean()
+}
+
+/// See [`Resultant::length`].
+pub fn mean_resultant_length<T: Scalar>(angles: impl IntoIterator<Item = Angle<T>>) -> T {
+    angles.into_iter().collect::<Resultant<T>>().length()
+}
+
+/// See [`Resultant::variance`].
+pub fn variance<T: Scalar>(angles: impl IntoIterator<Item = Angle<T>>) -> T {
+    angles.into_iter().collect::<Resultant<T>>().variance()
+}
+
+/// See [`Resultant::std_dev`].
+pub fn std_dev<T: Scalar>(angles: impl IntoIterator<Item = Angle<T>>) -> T {
+    angles.into_iter().collect::<Resultant<T>>().std_dev()
+}
+
+/// See [`Resultant::angular_deviation`].
+pub fn angular_deviation<T: Scalar>(angles: impl IntoIterator<Item = Angle<T>>) -> T {
+    angles
+        .into_iter()
+        .collect::<Resultant<T>>()
+        .angular_deviation()
+}
+
+fn distance<T: Scalar>(a: Angle<T>, b: Angle<T>) -> T {
+    (a - b).abs().as_radians()
+}
+
+/// Circular median: the sample with the smallest sum of angular distances to
+/// all other samples, `None` if there are no samples.
+///
+/// Takes `O(n^2)` time, which is fine for sensor buffers of tens of samples.
+pub fn median<T: Scalar>(angles: &[Angle<T>]) -> Option<Angle<T>> {
+    let total = |candidate: &Angle<T>| {
+        angles
+            .iter()
+            .fold(T::ZERO, |sum, angle| sum + distance(*candidate, *angle))
+    };
+
+    angles
+        .iter()
+        .map(|candidate| (candidate, total(candidate)))
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(candidate, _)| *candidate)
+}
+
+/// Circular mean after dropping `trim` fraction (clamped to `[0, 1)`) of the
+/// samples farthest from the [`median`].
+///
+/// Reorders `angles` by distance from the median.
+pub fn trimmed_mean<T: Scalar>(angles: &mut [Angle<T>], trim: T) -> Option<Angle<T>> {
+    let center = median(angles)?;
+
+    angles.sort_unstable_by(|a, b| distance(*a, center).total_cmp(&distance(*b, center)));
+
+    let dropped = (angles.len() as f64 * trim.to_f64().clamp(0.0, 1.0)) as usize;
+    let kept = (angles.len() - dropped).max(1);
+
+    mean(angles[..kept].iter().copied())
+}
+
+/// Moves samples within `threshold` from the [`median`] to the front of
+/// `angles`, keeping their order, and returns them.
+///
+/// Samples at distance exactly `threshold` are kept.
+pub fn reject_outliers<T: Scalar>(angles: &mut [Angle<T>], threshold: Angle<T>) -> &mut [Angle<T>] {
+    let Some(center) = median(angles) else {
+        return angles;
+    };
+
+    let threshold = threshold.abs().as_radians();
+    let mut kept = 0;
+
+    for i in 0..angles.len() {
+        if distance(angles[i], center) <= threshold {
+            angles.swap(kept, i);
+            kept += 1;
+        }
+    }
+
+    &mut angles[..kept]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use libm::fabs;
+
+    #[test]
+    fn mean_across_seam() {
+        let angles = [Angle::degrees(179.0), Angle::degrees(-179.0)];
+
+        assert!(mean(angles)
+            .unwrap()
+            .is_within(&Angle::degrees(180.0), Angle::degrees(0.001)));
+
+        let angles = [
+            Angle::<f32>::degrees(350.0),
+            Angle::degrees(10.0),
+            Angle::degrees(30.0),
+        ];
+
+        assert!(mean(angles)
+            .unwrap()
+            .is_within(&Angle::degrees(10.0), Angle::degrees(0.001)));
+    }
+
+    #[test]
+    fn undefined_mean() {
+        assert!(mean::<f64>([]).is_none());
+        assert!(mean([Angle::degrees(0.0), Angle::degrees(180.0)]).is_none());
+        assert!(mean([Angle::<f32>::degrees(90.0), Angle::degrees(-90.0)]).is_none());
+    }
+
+    #[test]
+    fn weighted() {
+        let angles = [(Angle::degrees(0.0), 3.0), (Angle::degrees(90.0), 3.0)];
+
+        assert!(weighted_mean(angles)
+            .unwrap()
+            .is_within(&Angle::degrees(45.0), Angle::degrees(0.001)));
+
+        let angles = [(Angle::degrees(170.0), 1.0), (Angle::degrees(-170.0), 0.0)];
+
+        assert!(weighted_mean(angles)
+            .unwrap()
+            .is_within(&Angle::degrees(170.0), Angle::degrees(0.001)));
+    }
+
+    #[test]
+    fn dispersion() {
+        let same = [Angle::degrees(42.0); 4];
+
+        assert!(fabs(mean_resultant_length(same) - 1.0) < 1e-12);
+        assert!(fabs(variance(same)) < 1e-12);
+        assert!(std_dev(same) < 1e-6);
+        assert!(angular_deviation(same) < 1e-6);
+
+        // R = cos(30deg) for two samples 60deg apart
+        let spread = [Angle::degrees(150.0), Angle::degrees(-150.0)];
+        let r = 0.8660254037844387;
+
+        assert!(fabs(mean_resultant_length(spread) - r) < 1e-12);
+        assert!(fabs(variance(spread) - (1.0 - r)) < 1e-12);
+        assert!(fabs(std_dev(spread) - libm::sqrt(-2.0 * libm::log(r))) < 1e-12);
+        assert!(fabs(angular_deviation(spread) - libm::sqrt(2.0 * (1.0 - r))) < 1e-12);
+
+        let uniform = [0.0, 90.0, 180.0, -90.0].map(Angle::degrees);
+
+        assert!(mean_resultant_length(uniform) < 1e-12);
+        assert_eq!(mean_resultant_length::<f64>([]), 0.0);
+    }
+
+    #[test]
+    fn identical_samples() {
+        for tenths in -1800..1800 {
+            for count in 1..16 {
+                let angles = core::iter::repeat_n(Angle::degrees(tenths as f64 / 10.0), count);
+                let resultant: Resultant = angles.clone().collect();
+
+                assert!(resultant.length() <= 1.0);
+                assert!(!resultant.std_dev().is_nan());
+                assert!(!resultant.angular_deviation().is_nan());
+
+                let angles = angles.map(|angle| Angle::<f32>::radians(angle.as_radians() as f32));
+                let resultant: Resultant<f32> = angles.collect();
+
+                assert!(resultant.length() <= 1.0);
+                assert!(!resultant.std_dev().is_nan());
+                assert!(!resultant.angular_deviation().is_nan());
+            }
+        }
+
+        assert_eq!(std_dev([Angle::degrees(0.2); 7]), 0.0);
+    }
+
+    #[test]
+    fn streaming() {
+        let mut resultant = Resultant::new();
+
+        resultant.add(Angle::degrees(179.0));
+        resultant.extend([Angle::degrees(-179.0)]);
+
+        assert!(resultant
+            .mean()
+            .unwrap()
+            .is_within(&Angle::degrees(180.0), Angle::degrees(0.001)));
+    }
+
+    #[test]
+    fn median_across_seam() {
+        let angles = [170.0, -175.0, 178.0, -179.0, 40.0].map(Angle::degrees);
+
+        assert_eq!(median(&angles), Some(Angle::degrees(178.0)));
+        assert_eq!(median::<f64>(&[]), None);
+        assert_eq!(
+            median(&[Angle::<f32>::degrees(10.0)]),
+            Some(Angle::degrees(10.0))
+        );
+    }
+
+    #[test]
+    fn trimmed() {
+        let mut angles =
+            [-2.0, 1.0, 0.0, 120.0, 2.0, -1.0, 100.0, 3.0, -3.0, 0.5].map(Angle::degrees);
+
+        let plain = mean(angles).unwrap();
+        let trimmed = trimmed_mean(&mut angles, 0.2).unwrap();
+
+        assert!(!plain.is_within(&Angle::degrees(0.05), Angle::degrees(1.0)));
+        assert!(trimmed.is_within(&Angle::degrees(0.0625), Angle::degrees(0.001)));
+        assert_eq!(trimmed_mean::<f64>(&mut [], 0.2), None);
+        assert!(trimmed_mean(&mut [Angle::degrees(5.0)], 1.0)
+            .unwrap()
+            .is_within(&Angle::degrees(5.0), Angle::degrees(0.001)));
+    }
+
+    #[test]
+    fn outliers() {
+        let mut angles = [179.0, -178.0, 60.0, 177.0, -179.0, -90.0].map(Angle::degrees);
+
+        let inliers = reject_outliers(&mut angles, Angle::degrees(5.0));
+
+        assert_eq!(inliers, [179.0, -178.0, 177.0, -179.0].map(Angle::degrees));
+        assert!(mean(inliers.iter().copied())
+            .unwrap()
+            .is_within(&Angle::degrees(179.75), Angle::degrees(0.001)));
+
+        assert!(reject_outliers::<f64>(&mut [], Angle::degrees(5.0)).is_empty());
+    }
+}
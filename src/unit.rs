@@ -0,0 +1,111 @@
+use crate::{Angle, Scalar, UnsignedAngle};
+
+/// Unit of a plain angle value.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "use_defmt", derive(defmt::Format))]
+pub enum Unit {
+    Radians,
+    Degrees,
+    Turns,
+    Gradians,
+    ArcMinutes,
+    ArcSeconds,
+    /// NATO mils, 6400 in a full turn.
+    Mils,
+}
+
+impl Unit {
+    /// Suffix written after values in this unit, e.g. `deg`.
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            Unit::Radians => "rad",
+            Unit::Degrees => "deg",
+            Unit::Turns => "turn",
+            Unit::Gradians => "grad",
+            Unit::ArcMinutes => "arcmin",
+            Unit::ArcSeconds => "arcsec",
+            Unit::Mils => "mil",
+        }
+    }
+
+    /// Recognizes [`Unit::suffix`], its plural and common symbols like `°`,
+    /// ignoring ASCII case.
+    pub fn from_suffix(suffix: &str) -> Option<Self> {
+        const SUFFIXES: [(&str, Unit); 17] = [
+            ("rad", Unit::Radians),
+            ("rads", Unit::Radians),
+            ("deg", Unit::Degrees),
+            ("°", Unit::Degrees),
+            ("turn", Unit::Turns),
+            ("turns", Unit::Turns),
+            ("rev", Unit::Turns),
+            ("grad", Unit::Gradians),
+            ("gon", Unit::Gradians),
+            ("arcmin", Unit::ArcMinutes),
+            ("'", Unit::ArcMinutes),
+            ("′", Unit::ArcMinutes),
+            ("arcsec", Unit::ArcSeconds),
+            ("\"", Unit::ArcSeconds),
+            ("″", Unit::ArcSeconds),
+            ("mil", Unit::Mils),
+            ("mils", Unit::Mils),
+        ];
+
+        SUFFIXES
+            .iter()
+            .find(|(s, _)| s.eq_ignore_ascii_case(suffix))
+            .map(|(_, unit)| *unit)
+    }
+}
+
+impl<T: Scalar> Angle<T> {
+    pub fn from_unit(value: T, unit: Unit) -> Self {
+        match unit {
+            Unit::Radians => Self::radians(value),
+            Unit::Degrees => Self::degrees(value),
+            Unit::Turns => Self::turns(value),
+            Unit::Gradians => Self::gradians(value),
+            Unit::ArcMinutes => Self::arcminutes(value),
+            Unit::ArcSeconds => Self::arcseconds(value),
+            Unit::Mils => Self::mils(value),
+        }
+    }
+
+    pub fn as_unit(&self, unit: Unit) -> T {
+        match unit {
+            Unit::Radians => self.as_radians(),
+            Unit::Degrees => self.as_degrees(),
+            Unit::Turns => self.as_turns(),
+            Unit::Gradians => self.as_gradians(),
+            Unit::ArcMinutes => self.as_arcminutes(),
+            Unit::ArcSeconds => self.as_arcseconds(),
+            Unit::Mils => self.as_mils(),
+        }
+    }
+
+    /// Same as [`Angle::as_unit`] folded into `[0, full turn)`, picking the
+    /// value converting back to the same [`UnsignedAngle`] if there is one.
+    pub(crate) fn as_unsigned_unit(&self, unit: Unit) -> T {
+        // angles folding to the same unsigned angle give the same value
+        let unsigned = UnsignedAngle::from(*self);
+        let value = Angle::from(unsigned).as_unit(unit);
+
+        if value >= T::ZERO {
+            return value + T::ZERO;
+        }
+
+        // adding full turn may round to a neighbouring value, or up to full
+        // turn for tiny negative values
+        let full_turn = Angle::<T>::HALF_TURN.as_unit(unit) * T::from_f64(2.0);
+        let value = value + full_turn;
+        let value = if value < full_turn { value } else { T::ZERO };
+        let ulp = value * T::EPSILON * T::from_f64(0.75);
+
+        [value, value - ulp, value + ulp]
+            .into_iter()
+            .find(|value| {
+                *value < full_turn && UnsignedAngle::from(Self::from_unit(*value, unit)) == unsigned
+            })
+            .unwrap_or(value)
+    }
+}
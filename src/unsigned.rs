@@ -0,0 +1,160 @@
+use crate::{Angle, Degrees, Radians, Scalar, Unit};
+use core::{
+    fmt::{Display, Formatter},
+    ops::{Add, Sub},
+};
+
+/// Angle normalized into the unsigned range `[0, 2pi)`.
+///
+/// Sibling of [`Angle`] for values conventionally expressed as `[0, 360)`
+/// degrees, such as compass headings or encoder positions. Converts to and
+/// from [`Angle`] with `From`, which only refolds the value. Like [`Angle`],
+/// NaN and infinite values are turned into a zero angle.
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct UnsignedAngle<T = f64> {
+    value: T,
+}
+
+impl<T: Scalar> UnsignedAngle<T> {
+    pub fn radians(value: Radians<T>) -> Self {
+        Self { value }.normalize()
+    }
+
+    pub fn degrees(value: Degrees<T>) -> Self {
+        Self::from(Angle::degrees(value))
+    }
+
+    pub fn as_radians(&self) -> Radians<T> {
+        self.value
+    }
+
+    /// Converts back exactly like [`Angle::as_degrees`], i.e.
+    /// `UnsignedAngle::degrees(a.as_degrees()) == a` for any angle created
+    /// from degrees.
+    pub fn as_degrees(&self) -> Degrees<T> {
+        Angle::from(*self).as_unsigned_unit(Unit::Degrees)
+    }
+
+    pub fn cos(&self) -> T {
+        self.as_radians().cos()
+    }
+
+    pub fn sin(&self) -> T {
+        self.as_radians().sin()
+    }
+
+    pub fn is_within(&self, other: &UnsignedAngle<T>, difference: Angle<T>) -> bool {
+        Angle::radians(self.value - other.value).is_within(&Angle::radians(T::ZERO), difference)
+    }
+
+    fn normalize(self) -> Self {
+        let value = Angle::radians(self.value).as_radians();
+
+        let value = if value < T::ZERO {
+            value + T::TAU
+        } else {
+            value
+        };
+
+        // adding 2pi to tiny negative value may round up to 2pi
+        let value = if value < T::TAU { value } else { T::ZERO };
+
+        Self { value }
+    }
+}
+
+impl<T: Scalar> Add for UnsignedAngle<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let value = self.value + rhs.value;
+        Self { value }.normalize()
+    }
+}
+
+impl<T: Scalar> Sub for UnsignedAngle<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let value = self.value - rhs.value;
+        Self { value }.normalize()
+    }
+}
+
+impl<T: Scalar> From<Angle<T>> for UnsignedAngle<T> {
+    fn from(angle: Angle<T>) -> Self {
+        Self::radians(angle.as_radians())
+    }
+}
+
+impl<T: Scalar> From<UnsignedAngle<T>> for Angle<T> {
+    fn from(angle: UnsignedAngle<T>) -> Self {
+        Angle::radians(angle.as_radians())
+    }
+}
+
+impl<T: Scalar + Display> Display for UnsignedAngle<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Angle::from(*self).display(Unit::Degrees).unsigned().fmt(f)
+    }
+}
+
+#[cfg(feature = "use_defmt")]
+impl<T: Scalar> defmt::Format for UnsignedAngle<T> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{=f32}deg", self.as_degrees().to_f32());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn norm() {
+        let a1 = UnsignedAngle::degrees(270.0);
+        let a2 = UnsignedAngle::degrees(-90.0);
+        let a3 = UnsignedAngle::degrees(270.0 + 360.0 * 7.0);
+
+        assert!((a1.as_degrees() - 270.0).abs() < 0.001);
+        assert!(a1.is_within(&a2, Angle::degrees(0.001)));
+        assert!(a1.is_within(&a3, Angle::degrees(0.001)));
+        assert!(UnsignedAngle::radians(-1e-20).as_radians() < core::f64::consts::TAU);
+    }
+
+    #[test]
+    fn add_sub() {
+        let a1 = UnsignedAngle::degrees(350.0);
+        let a2 = UnsignedAngle::degrees(20.0);
+
+        assert!((a1 + a2).is_within(&UnsignedAngle::degrees(10.0), Angle::degrees(0.001)));
+        assert!((a2 - a1).is_within(&UnsignedAngle::degrees(30.0), Angle::degrees(0.001)));
+        assert!((a1 - a2).as_degrees() > 329.999);
+    }
+
+    #[test]
+    fn conversions() {
+        let signed = Angle::degrees(-90.0);
+        let unsigned = UnsignedAngle::from(signed);
+
+        assert!((unsigned.as_degrees() - 270.0).abs() < 0.001);
+        assert!(Angle::from(unsigned).is_within(&signed, Angle::degrees(0.001)));
+    }
+
+    #[test]
+    fn degrees_round_trip() {
+        assert_eq!(UnsignedAngle::degrees(247.5).as_degrees(), 247.5);
+        assert_eq!(UnsignedAngle::degrees(-112.5).as_degrees(), 247.5);
+
+        for i in 0..100_000 {
+            let angle = UnsignedAngle::degrees(i as f64 * 0.0036000000123);
+            let degrees = angle.as_degrees();
+
+            assert!((0.0..360.0).contains(&degrees));
+            assert_eq!(UnsignedAngle::degrees(degrees), angle);
+
+            let angle = UnsignedAngle::<f32>::degrees(i as f32 * 0.0036);
+            assert_eq!(UnsignedAngle::degrees(angle.as_degrees()), angle);
+        }
+    }
+}
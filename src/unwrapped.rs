@@ -0,0 +1,103 @@
+use crate::{Angle, Degrees, Radians, Scalar};
+
+/// Multi-turn tracker accumulating successive normalized [`Angle`] samples.
+///
+/// Every new sample is assumed to be reached from the previous one along the
+/// shortest path, so consecutive samples must differ by less than half a turn.
+/// The number of seam crossings is kept as an integer, which keeps the
+/// continuous value exact no matter how many turns were made.
+#[derive(Copy, Clone, Debug)]
+pub struct UnwrappedAngle<T = f64> {
+    last: Angle<T>,
+    wraps: i32,
+}
+
+impl<T: Scalar> UnwrappedAngle<T> {
+    pub fn new(initial: Angle<T>) -> Self {
+        Self {
+            last: initial,
+            wraps: 0,
+        }
+    }
+
+    pub fn update(&mut self, sample: Angle<T>) {
+        let jump = sample.as_radians() - self.last.as_radians();
+
+        if jump > T::PI {
+            self.wraps -= 1;
+        } else if jump < -T::PI {
+            self.wraps += 1;
+        }
+
+        self.last = sample;
+    }
+
+    /// Number of completed full turns, rounded towards zero.
+    pub fn turns(&self) -> i32 {
+        let value = self.last.as_radians();
+
+        if self.wraps > 0 && value < T::ZERO {
+            self.wraps - 1
+        } else if self.wraps < 0 && value > T::ZERO {
+            self.wraps + 1
+        } else {
+            self.wraps
+        }
+    }
+
+    pub fn as_radians(&self) -> Radians<T> {
+        T::from_f64(self.wraps as f64) * T::TAU + self.last.as_radians()
+    }
+
+    pub fn as_degrees(&self) -> Degrees<T> {
+        self.as_radians().to_degrees()
+    }
+
+    /// Most recent sample, i.e. continuous value folded back into `(-pi, pi]`.
+    pub fn angle(&self) -> Angle<T> {
+        self.last
+    }
+}
+
+impl<T: Scalar> From<UnwrappedAngle<T>> for Angle<T> {
+    fn from(unwrapped: UnwrappedAngle<T>) -> Self {
+        unwrapped.angle()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multi_turn() {
+        let mut tracker = UnwrappedAngle::new(Angle::degrees(0.0));
+
+        for step in 1..=37 {
+            tracker.update(Angle::degrees(step as f64 * 30.0));
+        }
+
+        assert!((tracker.as_degrees() - 1110.0).abs() < 0.001);
+        assert_eq!(tracker.turns(), 3);
+        assert!(tracker
+            .angle()
+            .is_within(&Angle::degrees(30.0), Angle::degrees(0.001)));
+    }
+
+    #[test]
+    fn backwards() {
+        let mut tracker = UnwrappedAngle::new(Angle::degrees(170.0));
+
+        tracker.update(Angle::degrees(-170.0));
+        assert!((tracker.as_degrees() - 190.0).abs() < 0.001);
+        assert_eq!(tracker.turns(), 0);
+
+        for degrees in [90.0, 0.0, -90.0, 180.0, 90.0, 0.0, -90.0] {
+            tracker.update(Angle::degrees(degrees));
+        }
+
+        assert!((tracker.as_degrees() + 450.0).abs() < 0.001);
+        assert_eq!(tracker.turns(), -1);
+        assert!(Angle::from(tracker).is_within(&Angle::degrees(-90.0), Angle::degrees(0.001)));
+    }
+}